@@ -0,0 +1,397 @@
+//! Headless game runner. Drives the game from the terminal prompt without the WebView.
+//! Commands are read line by line, so a game can be scripted by piping a file to stdin:
+//!
+//!     svojak-cli path/to/pack.siq --hub manual --players Alice,Bob --fail-fast < smoke.txt
+
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+use std::thread::sleep;
+use std::time::Duration;
+
+use svojak_app::core::game::game_data::GameMode;
+use svojak_app::core::game::journal::HostCommand;
+use svojak_app::core::game_controller::{game, game_mut};
+use svojak_app::core::game_entities::{PlayerState, DEFAULT_ICON};
+use svojak_app::core::game_pack::game_pack_loader::load_game_pack;
+use svojak_app::host_api::dto::PlayerDto;
+use svojak_app::host_api::event_sink::subscribe;
+use svojak_app::host_api::websocket_sink::WebSocketSink;
+use svojak_app::hub::hub_api::HubType;
+use svojak_app::hub::hw::virtual_hw_hub::VIRTUAL_HUB_PORT;
+use svojak_app::player_server::entities::PsPlayer;
+use svojak_app::player_server::player_server::{ps, ps_mut};
+
+const DEFAULT_ROUND_DURATION_MIN: u64 = 10;
+
+const USAGE: &str = "Usage: svojak-cli <pack.siq> [--hub hw|virtual|web|manual] [--port <port>] \
+[--players <name,name,..>] [--round-min <minutes>] [--events-port <port>] [--fail-fast]";
+
+const HELP: &str = "\
+Setup:     players | start | wait <ms>
+Info:      status | board | help | quit
+Question:  select <price> <topic> | allow | request <id> | cancel | correct | wrong
+           show | finish | next
+Chooser:   chooser <id> | random | pip <id>
+Auction:   bid <id> <amount> | allin <id> | pass <id> | close
+Final:     eliminate <theme> | wager <id> <amount> | judge <id> correct|wrong
+Players:   score <id> <score> | add <id> <name> | retire <id> | move <id> <new id>
+Game:      undo | end";
+
+struct CliArgs {
+    pack_path: String,
+    hub_type: HubType,
+    port: String,
+    players: Vec<String>,
+    round_duration_min: u64,
+    /// Events are broadcast over WebSocket on this port
+    events_port: Option<u16>,
+    fail_fast: bool,
+}
+
+enum Prompt {
+    Host(HostCommand),
+    Start,
+    Wait(Duration),
+    Players,
+    Status,
+    Board,
+    Help,
+    Quit,
+}
+
+fn main() -> ExitCode {
+    if env::var("RUST_LOG").is_err() {
+        env::set_var("RUST_LOG", "warn")
+    }
+    env_logger::init();
+
+    let args = match parse_args(env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match load_game_pack(&args.pack_path) {
+        Ok(pack) => game_mut().set_game_pack(pack),
+        Err(e) => {
+            eprintln!("Can't load the pack: {:?}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+    if let Some(port) = args.events_port {
+        match WebSocketSink::bind(("0.0.0.0", port)) {
+            Ok(sink) => {
+                println!("Events are broadcast at ws://{}", sink.address());
+                subscribe(Box::new(sink));
+            }
+            Err(e) => {
+                eprintln!("Can't broadcast the events on port {}: {}", port, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    connect_hub(&args);
+    println!("Pack is loaded. Type 'help' for the commands");
+
+    run_prompt(&args)
+}
+
+fn parse_args(args: Vec<String>) -> Result<CliArgs, String> {
+    let mut args = args.into_iter();
+    let mut cli_args = CliArgs {
+        pack_path: args.next().ok_or("Pack path is missing")?,
+        hub_type: HubType::Manual,
+        port: String::new(),
+        players: vec![],
+        round_duration_min: DEFAULT_ROUND_DURATION_MIN,
+        events_port: None,
+        fail_fast: false,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("Value of {} is missing", arg));
+        match arg.as_str() {
+            "--hub" => {
+                cli_args.hub_type = match value()?.as_str() {
+                    "hw" => HubType::HwHub,
+                    "virtual" => {
+                        cli_args.port = VIRTUAL_HUB_PORT.to_string();
+                        HubType::HwHub
+                    }
+                    "web" => HubType::WebHub,
+                    "manual" => HubType::Manual,
+                    other => return Err(format!("Unknown hub type: {}", other)),
+                }
+            }
+            "--port" => cli_args.port = value()?,
+            "--players" => {
+                cli_args.players = value()?.split(',').map(|n| n.trim().to_string()).collect()
+            }
+            "--round-min" => {
+                cli_args.round_duration_min = value()?.parse().map_err(|_| "Bad round duration")?
+            }
+            "--events-port" => {
+                cli_args.events_port = Some(value()?.parse().map_err(|_| "Bad events port")?)
+            }
+            "--fail-fast" => cli_args.fail_fast = true,
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+    Ok(cli_args)
+}
+
+/// Players of the manual hub join the game right away, so a script may start it at once.
+/// Buzzers join as they are discovered
+fn connect_hub(args: &CliArgs) {
+    let mut server = ps_mut();
+    server.select_hub_type(args.hub_type.clone());
+    server.discover_hub_and_players(args.port.clone());
+
+    if args.hub_type == HubType::Manual {
+        let players: Vec<PlayerDto> = args
+            .players
+            .iter()
+            .enumerate()
+            .map(|(index, name)| PlayerDto {
+                id: index as i32 + 1,
+                iconPath: DEFAULT_ICON.to_string(),
+                name: name.clone(),
+                isUsed: true,
+                state: PlayerState::Idle,
+                score: 0,
+            })
+            .collect();
+        server.update_players(&players);
+        drop(server);
+
+        let players: Vec<PsPlayer> = players.iter().map(Into::into).collect();
+        if let Err(e) = game_mut().push_new_players(players) {
+            eprintln!("Can't add the players: {}", e.current_context());
+        }
+    }
+}
+
+fn run_prompt(args: &CliArgs) -> ExitCode {
+    let stdin = io::stdin();
+    print_prompt();
+    for line in stdin.lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            print_prompt();
+            continue;
+        }
+
+        let result = match parse_prompt(line) {
+            Ok(Prompt::Quit) => return ExitCode::SUCCESS,
+            Ok(prompt) => run(prompt, args),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            println!("Error: {}", e);
+            if args.fail_fast {
+                return ExitCode::FAILURE;
+            }
+        }
+        print_prompt();
+    }
+    ExitCode::SUCCESS
+}
+
+fn print_prompt() {
+    print!("[{}] > ", game().game_state.name());
+    io::stdout().flush().ok();
+}
+
+fn run(prompt: Prompt, args: &CliArgs) -> Result<(), String> {
+    match prompt {
+        Prompt::Host(command) => {
+            game_mut()
+                .run_host_command(command)
+                .map_err(|e| e.current_context().to_string())?;
+            print_status();
+        }
+        Prompt::Start => {
+            let game_mode = GameMode {
+                round_duration: Duration::from_secs(args.round_duration_min * 60),
+                pig_in_poke_enabled: true,
+                manual_buzzers: ps().is_manual_hub(),
+                ..Default::default()
+            };
+            game_mut()
+                .run_host_command(HostCommand::StartNewGame { game_mode })
+                .map_err(|e| e.current_context().to_string())?;
+            print_status();
+        }
+        Prompt::Wait(duration) => sleep(duration),
+        Prompt::Players => print_players(),
+        Prompt::Status => print_status(),
+        Prompt::Board => print_board(),
+        Prompt::Help => println!("{}", HELP),
+        Prompt::Quit => {}
+    }
+    Ok(())
+}
+
+fn parse_prompt(line: &str) -> Result<Prompt, String> {
+    let (word, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let params: Vec<&str> = rest.split_whitespace().collect();
+    let id = |index: usize| -> Result<u8, String> {
+        let param = params.get(index).ok_or("Player id is missing")?;
+        param
+            .parse()
+            .map_err(|_| format!("Bad player id: {}", param))
+    };
+    let number = |index: usize| -> Result<i32, String> {
+        let param = params.get(index).ok_or("Number is missing")?;
+        param.parse().map_err(|_| format!("Bad number: {}", param))
+    };
+
+    let command = match word {
+        "start" => return Ok(Prompt::Start),
+        "wait" => {
+            return Ok(Prompt::Wait(
+                Duration::from_millis(number(0)?.max(0) as u64),
+            ))
+        }
+        "players" => return Ok(Prompt::Players),
+        "status" => return Ok(Prompt::Status),
+        "board" => return Ok(Prompt::Board),
+        "help" => return Ok(Prompt::Help),
+        "quit" | "exit" => return Ok(Prompt::Quit),
+        "select" => {
+            let (price, topic) = rest
+                .trim()
+                .split_once(' ')
+                .ok_or("Price and topic expected")?;
+            HostCommand::SelectQuestion {
+                topic: topic.trim().to_string(),
+                price: price.parse().map_err(|_| format!("Bad price: {}", price))?,
+            }
+        }
+        "allow" => HostCommand::AllowAnswer,
+        "request" => HostCommand::RequestAnswer { player_id: id(0)? },
+        "cancel" => HostCommand::CancelAnswerRequests,
+        "correct" => HostCommand::AnswerQuestion {
+            answered_correctly: true,
+        },
+        "wrong" => HostCommand::AnswerQuestion {
+            answered_correctly: false,
+        },
+        "show" => HostCommand::StopAskingAndShowAnswer,
+        "finish" => HostCommand::FinishQuestion,
+        "next" => HostCommand::InitNextRound,
+        "chooser" => HostCommand::SetQuestionChooser { player_id: id(0)? },
+        "random" => HostCommand::PickRandomQuestionChooser,
+        "pip" => HostCommand::SendPipVictim { victim_id: id(0)? },
+        "bid" => HostCommand::PlaceAuctionBid {
+            player_id: id(0)?,
+            amount: number(1)?,
+        },
+        "allin" => HostCommand::PlaceAuctionAllIn { player_id: id(0)? },
+        "pass" => HostCommand::PassAuction { player_id: id(0)? },
+        "close" => HostCommand::FinishAuction,
+        "eliminate" => HostCommand::EliminateFinalTheme {
+            theme: rest.trim().to_string(),
+        },
+        "wager" => HostCommand::PlaceFinalWager {
+            player_id: id(0)?,
+            amount: number(1)?,
+        },
+        "judge" => HostCommand::JudgeFinalAnswer {
+            player_id: id(0)?,
+            answered_correctly: match params.get(1) {
+                Some(&"correct") => true,
+                Some(&"wrong") => false,
+                _ => return Err("Usage: judge <id> correct|wrong".to_string()),
+            },
+        },
+        "score" => HostCommand::EditPlayerScore {
+            player_id: id(0)?,
+            score: number(1)?,
+        },
+        "add" => HostCommand::AddPlayer {
+            player_id: id(0)?,
+            name: params[1..].join(" "),
+        },
+        "retire" => HostCommand::RetirePlayer { player_id: id(0)? },
+        "move" => HostCommand::MovePlayer {
+            player_id: id(0)?,
+            new_player_id: id(1)?,
+        },
+        "undo" => HostCommand::UndoLastAction,
+        "end" => HostCommand::FinishGame,
+        other => return Err(format!("Unknown command '{}'. Type 'help'", other)),
+    };
+    Ok(Prompt::Host(command))
+}
+
+fn print_players() {
+    let app = game();
+    let mut players = app.game_state.game_ctx_ref().players_ref_as_vec();
+    if players.is_empty() {
+        println!("No players yet");
+    }
+    players.sort_by_key(|p| p.term_id);
+    for player in players {
+        println!(
+            "  #{} {:<16} {:>6}  {:?}",
+            player.term_id, player.name, player.stats.score, player.state
+        );
+    }
+}
+
+fn print_status() {
+    {
+        let app = game();
+        let data = app.game_state.game_ctx_ref();
+        println!("State: {}", app.game_state.name());
+        if let Some(round) = data.current_round_opt_ref() {
+            println!(
+                "Round: {}. Questions left: {}",
+                round.name, round.questions_left
+            );
+        }
+        let question = data.current_question_ref();
+        if !question.topic.is_empty() {
+            let answer: Vec<&str> = question
+                .correct_answer
+                .iter()
+                .map(|a| a.content.as_str())
+                .collect();
+            println!(
+                "Question: {} for {}. Answer: {}",
+                question.topic,
+                data.current_question_price(),
+                answer.join(" ")
+            );
+        }
+    }
+    print_players();
+}
+
+fn print_board() {
+    let app = game();
+    let Some(round) = app.game_state.game_ctx_ref().current_round_opt_ref() else {
+        println!("No round is played");
+        return;
+    };
+
+    let mut topics: Vec<_> = round.topics.values().collect();
+    topics.sort_by(|a, b| a.name.cmp(&b.name));
+    for topic in topics {
+        let mut prices: Vec<i32> = topic
+            .questions
+            .values()
+            .filter(|q| !q.is_used)
+            .map(|q| q.price)
+            .collect();
+        prices.sort();
+        println!("  {:<24} {:?}", topic.name, prices);
+    }
+}
@@ -0,0 +1,245 @@
+use crate::core::game_entities::{GameplayError, Player, PlayerState};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AuctionBid {
+    pub player_id: u8,
+    pub amount: i32,
+    pub is_all_in: bool,
+}
+
+/// Bidding for the 'auction' question.
+/// Every alive player can raise the stake up to their score, go all-in or pass.
+/// The last player standing answers the question for the highest stake.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Auction {
+    pub nominal_price: i32,
+    pub chooser_id: u8,
+    pub participants: Vec<u8>,
+    pub passed_players: HashSet<u8>,
+    pub highest_bid: Option<AuctionBid>,
+}
+
+impl Auction {
+    pub fn new(nominal_price: i32, chooser_id: u8, players: &HashMap<u8, Player>) -> Self {
+        let mut participants: Vec<u8> = players
+            .values()
+            .filter(|p| p.state != PlayerState::Dead)
+            .map(|p| p.term_id)
+            .collect();
+        participants.sort();
+
+        Self {
+            nominal_price,
+            chooser_id,
+            participants,
+            passed_players: HashSet::default(),
+            highest_bid: None,
+        }
+    }
+
+    pub fn place_bid(&mut self, player: &Player, amount: i32) -> Result<(), GameplayError> {
+        self.ensure_can_bid(player.term_id)?;
+
+        if amount < self.nominal_price || amount <= self.highest_amount() {
+            log::info!(
+                "Bid of {} is too low. Nominal: {}, highest: {}",
+                amount,
+                self.nominal_price,
+                self.highest_amount()
+            );
+            return Err(GameplayError::InvalidBid);
+        }
+        if amount > player.stats.score {
+            log::info!(
+                "Player {} can't bid {} having score of {}",
+                player.term_id,
+                amount,
+                player.stats.score
+            );
+            return Err(GameplayError::InvalidBid);
+        }
+
+        self.highest_bid = Some(AuctionBid {
+            player_id: player.term_id,
+            amount,
+            is_all_in: amount == player.stats.score,
+        });
+        Ok(())
+    }
+
+    pub fn place_all_in(&mut self, player: &Player) -> Result<(), GameplayError> {
+        self.ensure_can_bid(player.term_id)?;
+
+        let amount = player.stats.score;
+        if amount <= 0 || amount <= self.highest_amount() {
+            log::info!(
+                "Player {} can't go all-in with score {}. Highest bid: {}",
+                player.term_id,
+                amount,
+                self.highest_amount()
+            );
+            return Err(GameplayError::InvalidBid);
+        }
+
+        self.highest_bid = Some(AuctionBid {
+            player_id: player.term_id,
+            amount,
+            is_all_in: true,
+        });
+        Ok(())
+    }
+
+    pub fn pass(&mut self, player_id: u8) -> Result<(), GameplayError> {
+        self.ensure_can_bid(player_id)?;
+
+        if self.leader_id() == Some(player_id) {
+            log::info!("Auction leader {} can't pass", player_id);
+            return Err(GameplayError::InvalidBid);
+        }
+
+        self.passed_players.insert(player_id);
+        Ok(())
+    }
+
+    /// Auction is decided when everyone except the leader has passed
+    pub fn is_decided(&self) -> bool {
+        let bidding_players = self
+            .participants
+            .iter()
+            .filter(|id| !self.passed_players.contains(*id))
+            .count();
+
+        match self.leader_id() {
+            Some(_) => bidding_players <= 1,
+            None => bidding_players == 0,
+        }
+    }
+
+    /// Player who answers the question. The question chooser plays it if nobody bid
+    pub fn winner_id(&self) -> u8 {
+        self.leader_id().unwrap_or(self.chooser_id)
+    }
+
+    /// Price the question is played for
+    pub fn stake(&self) -> i32 {
+        self.highest_bid
+            .as_ref()
+            .map(|b| b.amount)
+            .unwrap_or(self.nominal_price)
+    }
+
+    pub fn leader_id(&self) -> Option<u8> {
+        self.highest_bid.as_ref().map(|b| b.player_id)
+    }
+
+    /// Retired player drops out as if passed. The leader has to stay
+    pub fn remove_participant(&mut self, player_id: u8) -> Result<(), GameplayError> {
+        if self.leader_id() == Some(player_id) {
+            log::info!("Auction leader {} can't leave the auction", player_id);
+            return Err(GameplayError::OperationForbidden);
+        }
+        self.participants.retain(|&id| id != player_id);
+        self.passed_players.remove(&player_id);
+        Ok(())
+    }
+
+    pub fn move_player(&mut self, player_id: u8, new_player_id: u8) {
+        if self.chooser_id == player_id {
+            self.chooser_id = new_player_id;
+        }
+        if let Some(bid) = self
+            .highest_bid
+            .as_mut()
+            .filter(|b| b.player_id == player_id)
+        {
+            bid.player_id = new_player_id;
+        }
+        if self.passed_players.remove(&player_id) {
+            self.passed_players.insert(new_player_id);
+        }
+        for id in self.participants.iter_mut().filter(|id| **id == player_id) {
+            *id = new_player_id;
+        }
+        self.participants.sort();
+    }
+
+    fn highest_amount(&self) -> i32 {
+        self.highest_bid.as_ref().map(|b| b.amount).unwrap_or(0)
+    }
+
+    fn ensure_can_bid(&self, player_id: u8) -> Result<(), GameplayError> {
+        if !self.participants.contains(&player_id) {
+            log::info!("Player {} doesn't participate in the auction", player_id);
+            return Err(GameplayError::PlayerNotPresent(player_id.into()));
+        }
+        if self.passed_players.contains(&player_id) {
+            log::info!("Player {} has already passed", player_id);
+            return Err(GameplayError::InvalidBid);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::player;
+
+    fn auction(players: &[Player]) -> Auction {
+        let players = players.iter().map(|p| (p.term_id, p.clone())).collect();
+        Auction::new(200, 1, &players)
+    }
+
+    #[test]
+    fn test_bid_must_raise_and_fit_score() {
+        let (first, second) = (player(1, 1000), player(2, 300));
+        let mut auction = auction(&[first.clone(), second.clone()]);
+
+        assert!(auction.place_bid(&first, 100).is_err());
+        auction.place_bid(&first, 300).expect("Test");
+        assert!(auction.place_bid(&second, 300).is_err());
+        assert!(auction.place_bid(&second, 400).is_err());
+        assert_eq!(auction.stake(), 300);
+    }
+
+    #[test]
+    fn test_all_in_beats_lower_bid() {
+        let (first, second) = (player(1, 1000), player(2, 500));
+        let mut auction = auction(&[first.clone(), second.clone()]);
+
+        auction.place_bid(&first, 400).expect("Test");
+        auction.place_all_in(&second).expect("Test");
+        assert_eq!(auction.leader_id(), Some(2));
+        assert_eq!(auction.stake(), 500);
+        assert!(auction.highest_bid.as_ref().expect("Test").is_all_in);
+    }
+
+    #[test]
+    fn test_chooser_plays_for_nominal_when_everyone_passes() {
+        let (first, second) = (player(1, 1000), player(2, 500));
+        let mut auction = auction(&[first, second]);
+
+        auction.pass(1).expect("Test");
+        assert!(!auction.is_decided());
+        auction.pass(2).expect("Test");
+        assert!(auction.is_decided());
+        assert_eq!(auction.winner_id(), 1);
+        assert_eq!(auction.stake(), 200);
+    }
+
+    #[test]
+    fn test_leader_wins_when_others_pass() {
+        let (first, second, third) = (player(1, 1000), player(2, 500), player(3, 700));
+        let mut auction = auction(&[first, second.clone(), third]);
+
+        auction.place_bid(&second, 250).expect("Test");
+        assert!(auction.pass(2).is_err());
+        auction.pass(1).expect("Test");
+        auction.pass(3).expect("Test");
+        assert!(auction.is_decided());
+        assert_eq!(auction.winner_id(), 2);
+        assert_eq!(auction.stake(), 250);
+    }
+}
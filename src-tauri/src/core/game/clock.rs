@@ -0,0 +1,93 @@
+use crate::core::session::current_session;
+use crate::hub::hub_api::{calc_current_epoch_ms, HubManagerError};
+use error_stack::Result;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Event timestamp the simulated time starts from
+pub const SIMULATED_EPOCH_MS: u32 = 1_000_000;
+
+/// Source of the time for the game timers and answer timestamps.
+/// Simulated time stands still until it's advanced, so scripted games don't depend on the real time
+#[derive(Debug, Default)]
+pub struct GameClock {
+    simulated: Mutex<Option<SimulatedTime>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SimulatedTime {
+    origin: Instant,
+    elapsed: Duration,
+}
+
+impl GameClock {
+    /// Stops the time. From now on it only moves when advanced
+    pub fn simulate(&self) {
+        log::info!("Game clock is simulated");
+        *self.simulated() = Some(SimulatedTime {
+            origin: Instant::now(),
+            elapsed: Duration::ZERO,
+        });
+    }
+
+    /// Back to the system time
+    pub fn reset(&self) {
+        *self.simulated() = None;
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.simulated().is_some()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        match self.simulated().as_mut() {
+            Some(time) => time.elapsed += duration,
+            None => log::warn!("System time can't be advanced"),
+        }
+    }
+
+    pub fn now(&self) -> Instant {
+        match *self.simulated() {
+            Some(time) => time.origin + time.elapsed,
+            None => Instant::now(),
+        }
+    }
+
+    /// Timestamp comparable with the ones of the player events
+    pub fn epoch_ms(&self) -> Result<u32, HubManagerError> {
+        match *self.simulated() {
+            Some(time) => Ok(SIMULATED_EPOCH_MS + time.elapsed.as_millis() as u32),
+            None => calc_current_epoch_ms(),
+        }
+    }
+
+    fn simulated(&self) -> MutexGuard<Option<SimulatedTime>> {
+        self.simulated.lock().expect("Mutex is poisoned")
+    }
+}
+
+/// Time of the session the calling thread works with
+pub fn now() -> Instant {
+    current_session().clock().now()
+}
+
+pub fn epoch_ms() -> Result<u32, HubManagerError> {
+    current_session().clock().epoch_ms()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulated_time_moves_only_when_advanced() {
+        let clock = GameClock::default();
+        clock.simulate();
+        let started_at = clock.now();
+        assert_eq!(clock.epoch_ms().expect("Test"), SIMULATED_EPOCH_MS);
+
+        clock.advance(Duration::from_millis(1500));
+        assert_eq!(clock.now() - started_at, Duration::from_millis(1500));
+        assert_eq!(clock.epoch_ms().expect("Test"), SIMULATED_EPOCH_MS + 1500);
+    }
+}
@@ -0,0 +1,47 @@
+use crate::core::game::clock;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum CountdownKind {
+    /// Players can request the answer until it expires
+    BuzzWindow,
+    /// Answering player has to give the answer until it expires
+    Answer,
+    /// Question has to be chosen until it expires. Then a random player gets the turn
+    QuestionChoice,
+    /// Players can press for the first turn until it expires. Then a random player chooses first
+    FirstChooserPick,
+}
+
+/// Time limit of the current game state
+#[derive(Debug, Clone)]
+pub struct Countdown {
+    pub kind: CountdownKind,
+    duration: Duration,
+    started_at: Instant,
+}
+
+impl Countdown {
+    pub fn start(kind: CountdownKind, duration: Duration) -> Self {
+        log::debug!("Starting {:?} countdown for {:?}", kind, duration);
+        Self {
+            kind,
+            duration,
+            started_at: clock::now(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn remaining(&self) -> Duration {
+        let elapsed = clock::now().saturating_duration_since(self.started_at);
+        self.duration.saturating_sub(elapsed)
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+}
@@ -3,16 +3,12 @@ use crate::core::game::state_structs::*;
 use crate::core::game_entities::{GameplayError, Player, PlayerState};
 use crate::host_api::events::emit_players_by_players_map;
 use crate::hub::hub_api::{PlayerEvent, TermButtonState};
-use error_stack::ResultExt;
 use rocket::yansi::Paint;
 use std::any::type_name;
 use std::collections::HashMap;
 use std::marker::PhantomData;
-use std::thread::sleep;
-use std::time::Duration;
 
 pub const INVALID_PLAYER_ID: u8 = 0; // TODO: Consider using Option<u8> instead
-const FASTEST_CLICK_ITERATION_DUR: Duration = Duration::from_millis(200);
 
 #[derive(Debug, Clone)]
 pub struct GameCtx<State = SetupAndLoading> {
@@ -99,21 +95,32 @@ impl<State> GameCtx<State> {
 
 /// Player events processor
 impl<State> GameCtx<State> {
-    pub fn get_fastest_click_player_id(&mut self) -> error_stack::Result<u8, GameplayError> {
+    /// Doesn't wait for the clicks. Returns `None` if no relevant click arrived yet
+    pub fn get_fastest_click_player_id(
+        &mut self,
+    ) -> error_stack::Result<Option<u8>, GameplayError> {
         let active_players = self.active_players();
 
-        let id: u8 = match active_players.len() {
+        self.data.answering_member_id = None;
+        let id: Option<u8> = match active_players.len() {
             0 => Err(GameplayError::NoActivePlayersLeft)?,
             1 => {
                 let keys: Vec<u8> = active_players.keys().cloned().collect();
-                *keys.first().expect("Expected to have exactly one element")
+                Some(*keys.first().expect("Expected to have exactly one element"))
             }
-            _ => self
-                .calc_fastest_click(&active_players)
-                .change_context(GameplayError::HubOperationError)?,
+            _ => self.poll_fastest_click(&active_players).map(|term_id| {
+                let id = self.data.team_id_of(term_id);
+                if self.data.teams.contains_key(&id) {
+                    log::info!("Team {} member {} clicked first", id, term_id);
+                    self.data.answering_member_id = Some(term_id);
+                }
+                id
+            }),
         };
 
-        log::info!("Fastest click from user: {}", id);
+        if let Some(id) = id {
+            log::info!("Fastest click from user: {}", id);
+        }
         Ok(id)
     }
 
@@ -126,34 +133,41 @@ impl<State> GameCtx<State> {
             .collect()
     }
 
-    fn calc_fastest_click(
-        &self,
-        active_players: &HashMap<u8, Player>,
-    ) -> error_stack::Result<u8, GameplayError> {
+    fn poll_fastest_click(&mut self, active_players: &HashMap<u8, Player>) -> Option<u8> {
         let allow_answer_timestamp = self.data.allow_answer_timestamp;
-        loop {
-            sleep(FASTEST_CLICK_ITERATION_DUR);
-            let events = self.data.take_events();
-            if events.is_empty() {
-                log::debug!("No events. Waiting for the next iteration");
-                continue;
-            }
+        let events = self.data.take_events();
+        if events.is_empty() {
+            log::debug!("No events. Waiting for the next player events");
+            return None;
+        }
 
-            let filtered =
-                Self::filter_irrelevant_events(allow_answer_timestamp, events, active_players);
-            if filtered.is_empty() {
-                log::debug!("No events after filtering. Waiting for the next iteration");
-                continue;
-            }
+        let filtered: Vec<PlayerEvent> = self
+            .filter_irrelevant_events(allow_answer_timestamp, events, active_players)
+            .into_iter()
+            .filter(|e| {
+                let is_locked = self.data.false_starts.is_locked(e.term_id, e.timestamp);
+                if is_locked {
+                    log::debug!("Terminal {} is locked out. Skipping: {:?}", e.term_id, e);
+                }
+                !is_locked
+            })
+            .collect();
+        if filtered.is_empty() {
+            log::debug!("No events after filtering. Waiting for the next player events");
+            return None;
+        }
 
-            let sorted = Self::sort_by_timestamp(filtered);
-            if let Some(value) = sorted.first() {
-                return Ok(value.term_id);
-            }
+        let sorted = Self::sort_by_timestamp(filtered);
+        if self.data.answer_allowed {
+            sorted
+                .iter()
+                .for_each(|e| self.data.register_press(e.term_id, e.timestamp));
         }
+        sorted.first().map(|e| e.term_id)
     }
 
     fn filter_irrelevant_events(
+        &self,
         allow_answer_timestamp: u32,
         events: Vec<PlayerEvent>,
         players: &HashMap<u8, Player>,
@@ -177,7 +191,7 @@ impl<State> GameCtx<State> {
                 true
             })
             .filter(|&e| {
-                let Some(player) = players.get(&e.term_id) else {
+                let Some(player) = players.get(&self.data.team_id_of(e.term_id)) else {
                     log::debug!("Unknown terminal id {} event. Skipping: {:?}", e.term_id, e);
                     return false;
                 };
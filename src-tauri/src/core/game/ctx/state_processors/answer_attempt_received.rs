@@ -1,6 +1,7 @@
 use crate::core::game::ctx::game_ctx::GameCtx;
 use crate::core::game::state_structs::{AnswerAttemptReceived, DisplayQuestion, EndQuestion};
 use crate::core::game_entities::GameplayError;
+use crate::host_api::dto::QuestionType;
 
 pub enum AnswerQuestionResult {
     EndQuestion(GameCtx<EndQuestion>),
@@ -15,12 +16,14 @@ impl GameCtx<AnswerAttemptReceived> {
         self.process_stats(answered_correctly)?;
         self.update_non_active_player_states("AnswerAttemptReceived");
         let no_players_to_answer_left = self.no_players_to_answer_left();
+        let is_solo_answer = self.is_solo_answer();
         log::debug!(
-            "Answered correctly: {}, no players to answer left: {}",
+            "Answered correctly: {}, no players to answer left: {}, solo answer: {}",
             answered_correctly,
-            no_players_to_answer_left
+            no_players_to_answer_left,
+            is_solo_answer
         );
-        if answered_correctly || no_players_to_answer_left {
+        if answered_correctly || no_players_to_answer_left || is_solo_answer {
             log::info!("Removing correctly answered question from the pack");
             self.data.remove_current_question()?;
             Ok(AnswerQuestionResult::EndQuestion(self.transition()))
@@ -41,20 +44,56 @@ impl GameCtx<AnswerAttemptReceived> {
         }
 
         let player_id = data.active_player_id;
+        let price = data.current_question_price();
+        let rules = &data.game_mode.scoring_rules;
         let player = data
             .players
             .get_mut(&player_id)
-            .ok_or(GameplayError::PlayerNotPresent(player_id))?;
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
         if answered_correctly {
-            player.answered_correctly(&data.current_question);
+            player.answered_correctly(price);
+            data.last_correct_answerer_id = Some(player_id);
         } else {
-            player.answered_wrong(&data.current_question);
+            player.answered_wrong(price, rules);
         }
 
         log::info!("Answered player stats: {:?}", player);
+        self.process_member_stats(answered_correctly, price);
         Ok(())
     }
 
+    /// Keeps the breakdown of the team result by the member who clicked
+    fn process_member_stats(&mut self, answered_correctly: bool, price: i32) {
+        let data = &mut self.data;
+        let Some(member_id) = data.answering_member_id.take() else {
+            return;
+        };
+        let rules = &data.game_mode.scoring_rules;
+        let Some(member) = data
+            .teams
+            .get_mut(&data.active_player_id)
+            .and_then(|t| t.member_mut(member_id))
+        else {
+            log::debug!("Player {} isn't a member of the answering team", member_id);
+            return;
+        };
+
+        if answered_correctly {
+            member.answered_correctly(price);
+        } else {
+            member.answered_wrong(price, rules);
+        }
+    }
+
+    /// 'Pig in poke' victim and auction winner answer alone,
+    /// so the question ends after the first attempt
+    fn is_solo_answer(&self) -> bool {
+        matches!(
+            self.data.current_question.question_type,
+            QuestionType::PigInPoke | QuestionType::Auction
+        )
+    }
+
     fn no_players_to_answer_left(&self) -> bool {
         self.data.players.iter().all(|(_, p)| !p.can_answer())
     }
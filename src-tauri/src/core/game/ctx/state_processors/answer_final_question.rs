@@ -0,0 +1,63 @@
+use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::state_structs::{AnswerFinalQuestion, ShowRoundStats};
+use crate::core::game_entities::GameplayError;
+use crate::host_api::events::{emit_final_round, emit_players_by_players_map, emit_round_stats};
+
+impl GameCtx<AnswerFinalQuestion> {
+    pub fn judge_final_answer(
+        &mut self,
+        player_id: u8,
+        answered_correctly: bool,
+    ) -> Result<(), GameplayError> {
+        let final_round = self.data.final_round_mut()?;
+        final_round.judge_answer(player_id, answered_correctly)?;
+        log::info!(
+            "Final answer of player {} is judged. Correct: {}",
+            player_id,
+            answered_correctly
+        );
+        emit_final_round((&*final_round).into());
+        Ok(())
+    }
+
+    pub fn are_final_answers_judged(&self) -> bool {
+        self.data
+            .final_round
+            .as_ref()
+            .map(|f| f.all_answers_judged())
+            .unwrap_or(false)
+    }
+
+    /// Applies all the wagers at once, so no score changes before every answer is judged
+    pub fn settle_final_round(&mut self) -> Result<GameCtx<ShowRoundStats>, GameplayError> {
+        let data = &mut self.data;
+        let results = data.final_round_mut()?.results();
+        let rules = data.game_mode.scoring_rules.clone();
+
+        for (player_id, wager, answered_correctly) in results {
+            let stats = data.current_round_stats_mut();
+            stats.total_tries += 1;
+            if answered_correctly {
+                stats.total_correct_answers += 1;
+            } else {
+                stats.total_wrong_answers += 1;
+            }
+
+            let player = data
+                .players
+                .get_mut(&player_id)
+                .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
+            if answered_correctly {
+                player.answered_correctly(wager);
+            } else {
+                player.answered_wrong(wager, &rules);
+            }
+            log::info!("Final result of player: {:?}", player);
+        }
+        emit_players_by_players_map(&data.players);
+
+        data.remove_current_question()?;
+        emit_round_stats(data.to_round_stats_dto());
+        Ok(self.transition())
+    }
+}
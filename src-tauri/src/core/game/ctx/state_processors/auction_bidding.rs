@@ -0,0 +1,75 @@
+use crate::core::game::auction::Auction;
+use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::state_structs::{AnswerAttemptReceived, AuctionBidding};
+use crate::core::game_entities::{GameplayError, Player, PlayerState};
+use crate::host_api::events::emit_auction;
+
+impl GameCtx<AuctionBidding> {
+    pub fn place_auction_bid(&mut self, player_id: u8, amount: i32) -> Result<(), GameplayError> {
+        let player = self.player_clone(player_id)?;
+        log::info!("Player {} bids {}", player_id, amount);
+        self.update_auction(|auction| auction.place_bid(&player, amount))
+    }
+
+    pub fn place_auction_all_in(&mut self, player_id: u8) -> Result<(), GameplayError> {
+        let player = self.player_clone(player_id)?;
+        log::info!("Player {} goes all-in", player_id);
+        self.update_auction(|auction| auction.place_all_in(&player))
+    }
+
+    pub fn pass_auction(&mut self, player_id: u8) -> Result<(), GameplayError> {
+        log::info!("Player {} passes the auction", player_id);
+        self.update_auction(|auction| auction.pass(player_id))
+    }
+
+    pub fn is_auction_decided(&self) -> bool {
+        self.data
+            .auction
+            .as_ref()
+            .map(Auction::is_decided)
+            .unwrap_or(true)
+    }
+
+    /// Gives the question to the auction winner. Can be forced by the host before everyone passed
+    pub fn finish_auction(&mut self) -> Result<GameCtx<AnswerAttemptReceived>, GameplayError> {
+        let data = &mut self.data;
+        let auction = data
+            .auction
+            .as_ref()
+            .ok_or(GameplayError::OperationForbidden)?;
+        let winner_id = auction.winner_id();
+        log::info!(
+            "Auction is finished. Player {} plays the question for {}",
+            winner_id,
+            auction.stake()
+        );
+
+        data.set_active_player_state(PlayerState::Idle);
+        data.set_active_player_id(winner_id);
+        data.set_active_player_state(PlayerState::Answering);
+        data.answer_allowed = false;
+        Ok(self.transition())
+    }
+
+    fn player_clone(&self, player_id: u8) -> Result<Player, GameplayError> {
+        self.data
+            .players
+            .get(&player_id)
+            .cloned()
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))
+    }
+
+    fn update_auction<F>(&mut self, update: F) -> Result<(), GameplayError>
+    where
+        F: FnOnce(&mut Auction) -> Result<(), GameplayError>,
+    {
+        let auction = self
+            .data
+            .auction
+            .as_mut()
+            .ok_or(GameplayError::OperationForbidden)?;
+        update(auction)?;
+        emit_auction((&*auction).into());
+        Ok(())
+    }
+}
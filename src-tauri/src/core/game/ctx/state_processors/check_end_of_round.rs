@@ -1,4 +1,4 @@
-use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::ctx::game_ctx::{GameCtx, INVALID_PLAYER_ID};
 use crate::core::game::state_structs::{CheckEndOfRound, ChooseQuestion, ShowRoundStats};
 use crate::core::game_entities::{GameplayError, PlayerState};
 use crate::host_api::events::{emit_players_by_players_map, emit_round_stats};
@@ -10,8 +10,14 @@ pub enum CheckEndOfRoundResult {
 
 impl GameCtx<CheckEndOfRound> {
     pub fn check_end_of_round(&mut self) -> Result<CheckEndOfRoundResult, GameplayError> {
-        if self.data.current_round_ref().is_round_over() {
-            log::info!("Round is over! Transitioning to CalcRoundStats");
+        let is_time_over = self.data.round_clock.is_expired();
+        if is_time_over {
+            log::info!("Round time is over! Questions left are skipped");
+        }
+
+        if self.data.current_round_ref().is_round_over() || is_time_over {
+            log::info!("Round is over! Transitioning to ShowRoundStats");
+            self.data.round_clock.pause();
             emit_round_stats(self.data.to_round_stats_dto());
             self.kill_players_with_negative_scores();
             Ok(CheckEndOfRoundResult::ShowRoundStats(self.transition()))
@@ -21,14 +27,38 @@ impl GameCtx<CheckEndOfRound> {
                 "Round still has {} questions! Transitioning to ChooseQuestion",
                 questions_left
             );
-            log::debug!("Setting active player state to 'QuestionChooser'");
-            self.data
-                .set_active_player_state(PlayerState::QuestionChooser);
             self.reactivate_inactive_players();
+            self.pass_question_chooser_turn();
             Ok(CheckEndOfRoundResult::ChooseQuestion(self.transition()))
         }
     }
 
+    fn pass_question_chooser_turn(&mut self) {
+        let data = &mut self.data;
+        let policy = data.game_mode.question_chooser_policy;
+        let next_chooser_id = policy.next_chooser_id(
+            &data.players,
+            data.question_chooser_id,
+            data.last_correct_answerer_id,
+        );
+
+        match next_chooser_id {
+            Some(id) => {
+                log::debug!(
+                    "Player {} chooses the next question. Policy: {:?}",
+                    id,
+                    policy
+                );
+                data.set_question_chooser(id);
+            }
+            None => {
+                log::info!("Waiting for the host to pick the question chooser");
+                data.set_active_player_id(INVALID_PLAYER_ID);
+                data.question_chooser_id = None;
+            }
+        }
+    }
+
     fn reactivate_inactive_players(&mut self) {
         let game = &mut self.data;
         game.players.iter_mut().for_each(|(_, p)| {
@@ -38,7 +68,13 @@ impl GameCtx<CheckEndOfRound> {
         });
         emit_players_by_players_map(&game.players);
     }
+
     fn kill_players_with_negative_scores(&mut self) {
+        if !self.data.game_mode.scoring_rules.eliminate_negative_scores {
+            log::debug!("Elimination is off. Players with negative score stay in the game");
+            return;
+        }
+
         self.data.players.iter_mut().for_each(|(_, p)| {
             if p.stats.score < 0 {
                 log::info!(
@@ -1,12 +1,19 @@
+use crate::core::game::auction::Auction;
 use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::question_chooser::random_chooser_id;
 use crate::core::game::state_structs::{
-    AnswerAttemptReceived, ChooseQuestion, DisplayQuestion, WaitingForAnswerRequests,
+    AnswerAttemptReceived, AuctionBidding, ChooseQuestion, DisplayQuestion, PickPipVictim,
+    WaitingForAnswerRequests,
 };
 use crate::core::game_entities::{GameplayError, PlayerState};
+use crate::host_api::dto::QuestionType;
+use crate::host_api::events::emit_auction;
 
 pub enum ChooseQuestionResult {
     DisplayQuestion(GameCtx<DisplayQuestion>),
     AnswerAttemptReceived(GameCtx<AnswerAttemptReceived>),
+    PickPipVictim(GameCtx<PickPipVictim>),
+    AuctionBidding(GameCtx<AuctionBidding>),
 }
 
 impl GameCtx<ChooseQuestion> {
@@ -15,18 +22,39 @@ impl GameCtx<ChooseQuestion> {
         topic: &str,
         price: i32,
     ) -> Result<ChooseQuestionResult, GameplayError> {
+        if !self.data.players.contains_key(&self.data.active_player_id) {
+            log::info!("Nobody has the turn to choose the question");
+            return Err(GameplayError::NoQuestionChooser);
+        }
         let mut ctx: GameCtx<DisplayQuestion> = self.transition();
         let data = &mut ctx.data;
-        let question = data
+        data.chooser_picked_on_timeout = false;
+        let mut question = data
             .get_question(topic, price)
-            .map_err(Into::<GameplayError>::into)?;
+            .map_err(Into::<GameplayError>::into)?
+            .clone();
 
-        data.set_current_question(question.clone());
+        if question.question_type == QuestionType::PigInPoke && !data.game_mode.pig_in_poke_enabled
+        {
+            log::info!("Pig in poke mode is disabled. Playing question as a normal one");
+            question.question_type = QuestionType::Normal;
+        }
+        let question_type = question.question_type.clone();
+        data.set_current_question(question);
 
         log::info!("Picked question! Topic: {}, price: {}", topic, price);
 
         let player = data.active_player_id;
-        if data.game_mode.question_chooser_answers_first {
+        if question_type == QuestionType::PigInPoke {
+            log::info!("Pig in poke! Question chooser has to pick the victim");
+            Ok(ChooseQuestionResult::PickPipVictim(ctx.transition()))
+        } else if question_type == QuestionType::Auction {
+            log::info!("Auction! Players have to bid for the question");
+            let auction = Auction::new(data.current_question.price, player, &data.players);
+            emit_auction((&auction).into());
+            data.auction = Some(auction);
+            Ok(ChooseQuestionResult::AuctionBidding(ctx.transition()))
+        } else if data.game_mode.question_chooser_answers_first {
             let mut ctx: GameCtx<WaitingForAnswerRequests> = ctx.transition();
             let ctx = ctx.request_answer_by_player_id(player)?;
             Ok(ChooseQuestionResult::AnswerAttemptReceived(ctx))
@@ -35,4 +63,50 @@ impl GameCtx<ChooseQuestion> {
             Ok(ChooseQuestionResult::DisplayQuestion(ctx))
         }
     }
+
+    /// The host appoints the question chooser regardless of the policy
+    pub fn set_question_chooser(
+        &mut self,
+        player_id: u8,
+    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
+        self.data.ensure_can_choose_question(player_id)?;
+        log::info!(
+            "Host gives the turn to choose the question to player {}",
+            player_id
+        );
+        self.data.set_question_chooser(player_id);
+        Ok(self.transition())
+    }
+
+    /// Gives the turn to a random player when the question isn't chosen in time
+    pub fn pick_random_question_chooser(
+        &mut self,
+    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
+        let data = &mut self.data;
+        let player_id = random_chooser_id(&data.players, data.question_chooser_id)
+            .ok_or(GameplayError::NoActivePlayersLeft)?;
+
+        log::info!(
+            "Player {} is randomly picked to choose the question",
+            player_id
+        );
+        data.set_question_chooser(player_id);
+        Ok(self.transition())
+    }
+
+    /// Gives the turn to the player when the question isn't chosen in time.
+    /// There's no second chance: if the player is late too, a random question is played
+    pub fn pass_turn_on_timeout(
+        &mut self,
+        player_id: u8,
+    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
+        self.data.ensure_can_choose_question(player_id)?;
+        log::info!(
+            "Question isn't chosen in time. Player {} gets the turn",
+            player_id
+        );
+        self.data.set_question_chooser(player_id);
+        self.data.chooser_picked_on_timeout = true;
+        Ok(self.transition())
+    }
 }
@@ -1,15 +1,17 @@
+use crate::core::game::clock;
 use crate::core::game::ctx::game_ctx::{GameCtx, INVALID_PLAYER_ID};
+use crate::core::game::reaction_stats::AnswerRace;
 use crate::core::game::state_structs::{DisplayQuestion, EndQuestion, WaitingForAnswerRequests};
 use crate::core::game_entities::GameplayError;
 use crate::host_api::events::{emit_game_state_by_name, emit_players_by_game_data};
-use crate::hub::hub_api::calc_current_epoch_ms;
 
 impl GameCtx<DisplayQuestion> {
     pub fn allow_answer(&mut self) -> Result<GameCtx<WaitingForAnswerRequests>, GameplayError> {
         let game = self;
 
-        let timestamp = calc_current_epoch_ms().expect("Expected to calc epoch successfully");
+        let timestamp = clock::epoch_ms().expect("Expected to calc epoch successfully");
         game.data.allow_answer_timestamp = timestamp;
+        game.data.answer_race = AnswerRace::new(timestamp);
         log::info!("Current answer base timestamp: {}", timestamp);
 
         game.data.active_player_id = INVALID_PLAYER_ID;
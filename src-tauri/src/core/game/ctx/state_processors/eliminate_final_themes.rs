@@ -0,0 +1,55 @@
+use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::state_structs::{EliminateFinalThemes, PlaceFinalWagers};
+use crate::core::game_entities::{GamePackError, GameplayError, PlayerState};
+use crate::host_api::events::emit_final_round;
+
+pub enum EliminateThemeResult {
+    EliminateFinalThemes(GameCtx<EliminateFinalThemes>),
+    PlaceFinalWagers(GameCtx<PlaceFinalWagers>),
+}
+
+impl GameCtx<EliminateFinalThemes> {
+    pub fn eliminate_final_theme(
+        &mut self,
+        theme: &str,
+    ) -> Result<EliminateThemeResult, GameplayError> {
+        let data = &mut self.data;
+        let final_round = data.final_round_mut()?;
+        final_round.eliminate_theme(theme)?;
+        let next_eliminator_id = final_round.eliminator_id();
+        emit_final_round((&*final_round).into());
+        log::info!(
+            "Player {} eliminated theme '{}'",
+            data.active_player_id,
+            theme
+        );
+
+        let prices: Vec<i32> = data
+            .current_round_ref()
+            .topics
+            .get(theme)
+            .ok_or(GamePackError::TopicNotPresent)?
+            .questions
+            .keys()
+            .copied()
+            .collect();
+        for price in prices {
+            data.use_question(theme, price)?;
+        }
+
+        data.set_active_player_state(PlayerState::Idle);
+        match next_eliminator_id {
+            Some(id) => {
+                data.set_active_player_id(id);
+                data.set_active_player_state(PlayerState::QuestionChooser);
+                Ok(EliminateThemeResult::EliminateFinalThemes(
+                    self.transition(),
+                ))
+            }
+            None => {
+                log::info!("Final theme is picked. Players place their wagers");
+                Ok(EliminateThemeResult::PlaceFinalWagers(self.transition()))
+            }
+        }
+    }
+}
@@ -21,14 +21,14 @@ impl GameCtx<EndTheGame> {
         let the_rest = players
             .iter()
             .skip(3)
-            .map(Into::into)
+            .map(|p| self.final_stats(p))
             .collect::<Vec<PlayerFinalStatsDto>>();
 
         let final_stats = EndGameStatsDto {
             endGameReason: reason,
-            first: first.into(),
-            second: second.into(),
-            third: third_opt.map(Into::into),
+            first: self.final_stats(first),
+            second: self.final_stats(second),
+            third: third_opt.map(|p| self.final_stats(p)),
             theRest: the_rest,
         };
         emit_final_results(final_stats);
@@ -38,7 +38,7 @@ impl GameCtx<EndTheGame> {
         let mut ctx = self.transition();
         ctx.data = GameData::new(
             ctx.data
-                .players_ref_as_vec()
+                .individual_players()
                 .iter()
                 .map(|&p| Player {
                     state: PlayerState::default(),
@@ -50,4 +50,8 @@ impl GameCtx<EndTheGame> {
         );
         Ok(ctx)
     }
+
+    fn final_stats(&self, player: &Player) -> PlayerFinalStatsDto {
+        PlayerFinalStatsDto::from(player).with_members(self.data.team_members(player.term_id))
+    }
 }
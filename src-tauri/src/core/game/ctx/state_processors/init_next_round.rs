@@ -1,11 +1,71 @@
+use crate::core::game::clock;
 use crate::core::game::ctx::game_ctx::GameCtx;
-use crate::core::game::state_structs::{PickFirstQuestionChooser, StartNextRound};
-use crate::core::game_entities::GameplayError;
+use crate::core::game::final_round::FinalRound;
+use crate::core::game::round_clock::RoundClock;
+use crate::core::game::state_structs::{
+    EliminateFinalThemes, PickFirstQuestionChooser, PlaceFinalWagers, ShowRoundStats,
+    StartNextRound,
+};
+use crate::core::game_entities::{GameplayError, PlayerState};
+use crate::host_api::events::{emit_final_round, emit_players_by_players_map, emit_round_stats};
+
+pub enum InitNextRoundResult {
+    PickFirstQuestionChooser(GameCtx<PickFirstQuestionChooser>),
+    EliminateFinalThemes(GameCtx<EliminateFinalThemes>),
+    PlaceFinalWagers(GameCtx<PlaceFinalWagers>),
+    ShowRoundStats(GameCtx<ShowRoundStats>),
+}
 
 impl GameCtx<StartNextRound> {
-    pub fn init_next_round(&mut self) -> Result<GameCtx<PickFirstQuestionChooser>, GameplayError> {
+    pub fn init_next_round(&mut self) -> Result<InitNextRoundResult, GameplayError> {
         let game = &mut self.data;
         game.set_next_round();
-        Ok(self.transition())
+        game.round_clock = RoundClock::start(game.game_mode.round_duration);
+        game.start_round_reactions();
+        if !game.current_round_ref().is_final() {
+            game.allow_answer_timestamp = clock::epoch_ms().expect("No epoch today");
+            return Ok(InitNextRoundResult::PickFirstQuestionChooser(
+                self.transition(),
+            ));
+        }
+
+        let themes = game.current_round_ref().topics.keys().cloned().collect();
+        let final_round = FinalRound::new(&game.players, themes);
+        if final_round.participants.is_empty() {
+            log::info!("Nobody has positive score to play the final round. Skipping it");
+            emit_round_stats(game.to_round_stats_dto());
+            return Ok(InitNextRoundResult::ShowRoundStats(self.transition()));
+        }
+
+        log::info!(
+            "Final round! Participants in elimination order: {:?}",
+            final_round.participants
+        );
+        game.players.values_mut().for_each(|p| {
+            if p.state == PlayerState::Dead {
+                return;
+            }
+            p.state = if final_round.is_participant(p.term_id) {
+                PlayerState::Idle
+            } else {
+                PlayerState::Inactive
+            };
+        });
+        emit_players_by_players_map(&game.players);
+
+        let eliminator_id = final_round.eliminator_id();
+        emit_final_round((&final_round).into());
+        game.final_round = Some(final_round);
+        match eliminator_id {
+            Some(id) => {
+                game.set_active_player_id(id);
+                game.set_active_player_state(PlayerState::QuestionChooser);
+                Ok(InitNextRoundResult::EliminateFinalThemes(self.transition()))
+            }
+            None => {
+                log::info!("Final round has single theme. Players place their wagers");
+                Ok(InitNextRoundResult::PlaceFinalWagers(self.transition()))
+            }
+        }
     }
 }
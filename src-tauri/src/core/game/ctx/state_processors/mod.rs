@@ -1,11 +1,16 @@
 pub mod answer_attempt_received;
+pub mod answer_final_question;
+pub mod auction_bidding;
 pub mod check_end_of_round;
 pub mod choose_question;
 pub mod display_question;
+pub mod eliminate_final_themes;
 mod end_game;
 pub mod finish_question;
-mod init_next_round;
+pub mod init_next_round;
+pub mod pick_pip_victim;
 pub mod pick_question_chooser;
+pub mod place_final_wagers;
 pub mod setup_and_loading;
 pub mod show_round_stats;
 pub mod waiting_for_answer_requests;
@@ -0,0 +1,42 @@
+use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::state_structs::{AnswerAttemptReceived, PickPipVictim};
+use crate::core::game_entities::{GameplayError, PlayerState};
+
+impl GameCtx<PickPipVictim> {
+    pub fn send_pip_victim(
+        &mut self,
+        victim_id: u8,
+    ) -> Result<GameCtx<AnswerAttemptReceived>, GameplayError> {
+        let data = &mut self.data;
+        let chooser_id = data.active_player_id;
+        let victim = data
+            .players
+            .get(&victim_id)
+            .ok_or(GameplayError::PlayerNotPresent(victim_id.into()))?;
+
+        if victim.state == PlayerState::Dead {
+            log::info!("Player {} is dead and can't be a victim", victim_id);
+            return Err(GameplayError::AnswerForbidden);
+        }
+
+        let other_victims_present = data
+            .players
+            .values()
+            .any(|p| p.term_id != chooser_id && p.state != PlayerState::Dead);
+        if victim_id == chooser_id && other_victims_present {
+            log::info!("Question chooser can't give the pig in poke to themselves");
+            return Err(GameplayError::AnswerForbidden);
+        }
+
+        log::info!(
+            "Player {} gives pig in poke to player {}",
+            chooser_id,
+            victim_id
+        );
+        data.set_active_player_state(PlayerState::Idle);
+        data.set_active_player_id(victim_id);
+        data.set_active_player_state(PlayerState::Target);
+        data.answer_allowed = false;
+        Ok(self.transition())
+    }
+}
@@ -1,24 +1,55 @@
 use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::question_chooser::random_chooser_id;
 use crate::core::game::state_structs::{ChooseQuestion, PickFirstQuestionChooser};
-use crate::core::game_entities::{GameplayError, PlayerState};
+use crate::core::game_entities::GameplayError;
 use crate::host_api::events::emit_message;
-use crate::hub::hub_api::calc_current_epoch_ms;
 
 impl GameCtx<PickFirstQuestionChooser> {
+    /// Returns `None` until somebody clicks
     pub fn pick_first_question_chooser(
         &mut self,
-    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
-        self.data.allow_answer_timestamp = calc_current_epoch_ms().expect("No epoch today");
-
+    ) -> Result<Option<GameCtx<ChooseQuestion>>, GameplayError> {
         let term_id = match self.get_fastest_click_player_id() {
-            Ok(id) => id,
+            Ok(Some(id)) => id,
+            Ok(None) => return Ok(None),
             Err(err) => Err(err.current_context().clone())?,
         };
 
         emit_message(format!("Fastest player with id: {}", term_id));
-        self.data.set_active_player_by_id(term_id);
-        self.data
-            .set_active_player_state(PlayerState::QuestionChooser);
+        self.data.answering_member_id = None;
+        self.data.set_question_chooser(term_id);
+        Ok(Some(self.transition()))
+    }
+
+    /// The host picks the first chooser when the buzzers are silent or not used
+    pub fn set_first_question_chooser(
+        &mut self,
+        player_id: u8,
+    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
+        self.data.ensure_can_choose_question(player_id)?;
+        log::info!(
+            "Host picks player {} to choose the first question",
+            player_id
+        );
+        self.data.take_events();
+        self.data.set_question_chooser(player_id);
+        Ok(self.transition())
+    }
+
+    pub fn pick_random_first_question_chooser(
+        &mut self,
+    ) -> Result<GameCtx<ChooseQuestion>, GameplayError> {
+        let data = &mut self.data;
+        let player_id =
+            random_chooser_id(&data.players, None).ok_or(GameplayError::NoActivePlayersLeft)?;
+
+        log::info!(
+            "Player {} is randomly picked to choose the first question",
+            player_id
+        );
+        emit_message(format!("Random player with id: {}", player_id));
+        data.take_events();
+        data.set_question_chooser(player_id);
         Ok(self.transition())
     }
 }
@@ -0,0 +1,58 @@
+use crate::core::game::ctx::game_ctx::GameCtx;
+use crate::core::game::state_structs::{AnswerFinalQuestion, PlaceFinalWagers};
+use crate::core::game_entities::{GamePackError, GameplayError, PlayerState};
+use crate::host_api::events::{emit_final_round, emit_players_by_players_map};
+
+impl GameCtx<PlaceFinalWagers> {
+    /// Wager is hidden from other players. Only the fact of placing it is emitted
+    pub fn place_final_wager(&mut self, player_id: u8, amount: i32) -> Result<(), GameplayError> {
+        let data = &mut self.data;
+        let player = data
+            .players
+            .get(&player_id)
+            .cloned()
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
+
+        let final_round = data.final_round_mut()?;
+        final_round.place_wager(&player, amount)?;
+        log::info!("Player {} placed the final wager", player_id);
+        emit_final_round((&*final_round).into());
+        Ok(())
+    }
+
+    pub fn are_final_wagers_placed(&self) -> bool {
+        self.data
+            .final_round
+            .as_ref()
+            .map(|f| f.all_wagers_placed())
+            .unwrap_or(false)
+    }
+
+    pub fn show_final_question(&mut self) -> Result<GameCtx<AnswerFinalQuestion>, GameplayError> {
+        let data = &mut self.data;
+        let final_round = data.final_round_mut()?;
+        let theme = final_round
+            .final_theme()
+            .cloned()
+            .ok_or(GamePackError::TopicNotPresent)?;
+        let participants = final_round.participants.clone();
+
+        let question = data
+            .current_round_ref()
+            .topics
+            .get(&theme)
+            .and_then(|t| t.questions.values().find(|q| !q.is_used))
+            .cloned()
+            .ok_or(GamePackError::QuestionNotPresent)?;
+        log::info!("Final question of theme '{}' is displayed", theme);
+        data.set_current_question(question);
+
+        data.players
+            .values_mut()
+            .filter(|p| participants.contains(&p.term_id))
+            .for_each(|p| p.state = PlayerState::Answering);
+        emit_players_by_players_map(&data.players);
+        data.answer_allowed = false;
+        Ok(self.transition())
+    }
+}
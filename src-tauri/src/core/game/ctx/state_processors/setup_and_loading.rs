@@ -4,6 +4,7 @@ use crate::core::game::state_structs::{SetupAndLoading, StartNextRound};
 use crate::core::game_entities::GameplayError;
 use crate::core::game_pack::pack_content_entities::PackContent;
 use crate::host_api::events::emit_message;
+use std::time::Duration;
 
 impl GameCtx<SetupAndLoading> {
     pub fn set_round_duration(&mut self, round_duration_min: i32) {
@@ -24,6 +25,15 @@ impl GameCtx<SetupAndLoading> {
         let game = &mut ctx.data;
         game.set_pack_content(pack_content);
         game.game_mode = game_mode;
+        if game.game_mode.round_duration.is_zero() && game.round_duration_min > 0 {
+            log::info!(
+                "Using saved round duration of {} min",
+                game.round_duration_min
+            );
+            game.game_mode.round_duration =
+                Duration::from_secs(game.round_duration_min as u64 * 60);
+        }
+        game.form_teams()?;
         if game.players.len() < 2 {
             log::info!("Not enough players to run the game.");
             return Err(GameplayError::NotEnoughPlayers);
@@ -1,6 +1,12 @@
 use crate::core::game::ctx::game_ctx::GameCtx;
-use crate::core::game::state_structs::{AnswerAttemptReceived, WaitingForAnswerRequests};
+use crate::core::game::state_structs::{
+    AnswerAttemptReceived, DisplayQuestion, EndQuestion, WaitingForAnswerRequests,
+};
 use crate::core::game_entities::{GameplayError, PlayerState};
+use crate::host_api::dto::FalseStartDto;
+use crate::host_api::events::emit_false_start;
+use crate::hub::hub_api::TermButtonState;
+use std::collections::HashSet;
 
 impl GameCtx<WaitingForAnswerRequests> {
     pub fn request_answer_by_player_id(
@@ -12,4 +18,98 @@ impl GameCtx<WaitingForAnswerRequests> {
         self.data.answer_allowed = false;
         Ok(self.transition())
     }
+
+    /// Locks out players who pressed the button within the false start window
+    /// before the answer was allowed. Returns newly locked out terminals
+    pub fn register_false_starts(&mut self) -> Vec<u8> {
+        let Some(rule) = self.data.game_mode.false_start.clone() else {
+            return vec![];
+        };
+        let allow_answer_timestamp = self.data.allow_answer_timestamp;
+        let window_start = allow_answer_timestamp.saturating_sub(rule.window.as_millis() as u32);
+        let locked_until = allow_answer_timestamp.saturating_add(rule.penalty.as_millis() as u32);
+
+        let offenders: HashSet<u8> = self
+            .data
+            .peek_events()
+            .iter()
+            .filter(|e| e.state == TermButtonState::Pressed)
+            .filter(|e| e.timestamp >= window_start && e.timestamp < allow_answer_timestamp)
+            .map(|e| e.term_id)
+            .filter(|id| {
+                !self
+                    .data
+                    .false_starts
+                    .is_locked(*id, allow_answer_timestamp)
+            })
+            .filter(|id| {
+                self.data
+                    .players
+                    .get(&self.data.team_id_of(*id))
+                    .map(|p| p.allowed_to_click())
+                    .unwrap_or(false)
+            })
+            .collect();
+
+        offenders
+            .into_iter()
+            .map(|id| {
+                log::info!(
+                    "False start of player {}. Locked out for {:?}",
+                    id,
+                    rule.penalty
+                );
+                self.data.false_starts.lock(id, locked_until);
+                let player = &self.data.players[&self.data.team_id_of(id)];
+                emit_false_start(FalseStartDto {
+                    playerId: id as i32,
+                    playerName: player.name.clone(),
+                    isLockedOut: true,
+                    lockoutMs: rule.penalty.as_millis() as i32,
+                });
+                id
+            })
+            .collect()
+    }
+
+    /// Host gives the answer to the player regardless of the clicks
+    pub fn override_answer_request(
+        &mut self,
+        player_id: u8,
+    ) -> Result<GameCtx<AnswerAttemptReceived>, GameplayError> {
+        let player = self
+            .data
+            .players
+            .get(&player_id)
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
+        if !player.allowed_to_click() {
+            log::info!(
+                "Player {} can't answer in state {:?}",
+                player_id,
+                player.state
+            );
+            return Err(GameplayError::AnswerForbidden);
+        }
+
+        log::info!("Host gives the answer to player {}", player_id);
+        self.data.take_events();
+        self.data.answering_member_id = None;
+        self.request_answer_by_player_id(player_id)
+    }
+
+    /// Host stops waiting for the clicks. The answer can be allowed again
+    pub fn cancel_answer_requests(&mut self) -> Result<GameCtx<DisplayQuestion>, GameplayError> {
+        log::info!("Host cancelled answer requests");
+        self.data.answer_allowed = false;
+        self.data.take_events();
+        Ok(self.transition())
+    }
+
+    /// Nobody requested the answer within the buzz window or the host stopped asking
+    pub fn finish_question_preemptively(&mut self) -> Result<GameCtx<EndQuestion>, GameplayError> {
+        log::info!("Removing not answered question from the pack");
+        self.data.answer_allowed = false;
+        self.data.remove_current_question()?;
+        Ok(self.transition())
+    }
 }
@@ -0,0 +1,60 @@
+use rgb::RGB8;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Terminal color of the player locked out for the false start
+pub const FALSE_START_TERM_COLOR: RGB8 = RGB8 {
+    r: 255,
+    g: 0,
+    b: 255,
+};
+pub const TERM_COLOR_OFF: RGB8 = RGB8 { r: 0, g: 0, b: 0 };
+
+/// Press within `window` before the answer is allowed locks the terminal for `penalty`
+/// counting from the moment the answer is allowed
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FalseStartRule {
+    pub window: Duration,
+    pub penalty: Duration,
+}
+
+/// Terminals locked out for the false start. Timestamps share the base of `PlayerEvent::timestamp`
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct FalseStarts {
+    locked_until: HashMap<u8, u32>,
+}
+
+impl FalseStarts {
+    pub fn lock(&mut self, term_id: u8, until: u32) {
+        self.locked_until.insert(term_id, until);
+    }
+
+    pub fn is_locked(&self, term_id: u8, timestamp: u32) -> bool {
+        self.locked_until
+            .get(&term_id)
+            .map(|&until| timestamp < until)
+            .unwrap_or(false)
+    }
+
+    /// Lockout follows the player moved to another terminal
+    pub fn move_lock(&mut self, term_id: u8, new_term_id: u8) {
+        if let Some(until) = self.locked_until.remove(&term_id) {
+            self.locked_until.insert(new_term_id, until);
+        }
+    }
+
+    /// Removes the lockouts which are over and returns their terminals
+    pub fn release_expired(&mut self, now: u32) -> Vec<u8> {
+        let expired: Vec<u8> = self
+            .locked_until
+            .iter()
+            .filter(|(_, &until)| now >= until)
+            .map(|(&id, _)| id)
+            .collect();
+        expired.iter().for_each(|id| {
+            self.locked_until.remove(id);
+        });
+        expired
+    }
+}
@@ -0,0 +1,215 @@
+use crate::core::game_entities::{GamePackError, GameplayError, Player, PlayerState};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// State of the 'final' round.
+/// Players with positive score take turns eliminating themes until one is left.
+/// Then everyone places a hidden wager, answers the last question and all scores settle at once.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FinalRound {
+    /// Participants in the theme elimination order
+    pub participants: Vec<u8>,
+    pub themes: Vec<String>,
+    turn: usize,
+    wagers: HashMap<u8, i32>,
+    verdicts: HashMap<u8, bool>,
+}
+
+impl FinalRound {
+    /// Player with the lowest score eliminates the first theme
+    pub fn new(players: &HashMap<u8, Player>, mut themes: Vec<String>) -> Self {
+        let mut participants: Vec<&Player> = players
+            .values()
+            .filter(|p| p.state != PlayerState::Dead && p.stats.score > 0)
+            .collect();
+        participants.sort_by_key(|p| (p.stats.score, p.term_id));
+        themes.sort();
+
+        Self {
+            participants: participants.iter().map(|p| p.term_id).collect(),
+            themes,
+            ..Default::default()
+        }
+    }
+
+    pub fn is_participant(&self, player_id: u8) -> bool {
+        self.participants.contains(&player_id)
+    }
+
+    /// Player whose turn is to eliminate the theme
+    pub fn eliminator_id(&self) -> Option<u8> {
+        if self.is_theme_picked() || self.participants.is_empty() {
+            return None;
+        }
+        Some(self.participants[self.turn % self.participants.len()])
+    }
+
+    pub fn eliminate_theme(&mut self, theme: &str) -> Result<(), GameplayError> {
+        if self.is_theme_picked() {
+            log::info!("Final theme is already picked");
+            return Err(GameplayError::OperationForbidden);
+        }
+        let index = self
+            .themes
+            .iter()
+            .position(|t| t == theme)
+            .ok_or(GamePackError::TopicNotPresent)?;
+
+        self.themes.remove(index);
+        self.turn += 1;
+        Ok(())
+    }
+
+    pub fn is_theme_picked(&self) -> bool {
+        self.themes.len() <= 1
+    }
+
+    pub fn final_theme(&self) -> Option<&String> {
+        self.themes.first().filter(|_| self.is_theme_picked())
+    }
+
+    pub fn place_wager(&mut self, player: &Player, amount: i32) -> Result<(), GameplayError> {
+        if !self.is_participant(player.term_id) {
+            log::info!("Player {} doesn't participate in the final", player.term_id);
+            return Err(GameplayError::PlayerNotPresent(player.term_id.into()));
+        }
+        if amount < 1 || amount > player.stats.score {
+            log::info!(
+                "Player {} can't wager {} having score of {}",
+                player.term_id,
+                amount,
+                player.stats.score
+            );
+            return Err(GameplayError::InvalidWager);
+        }
+
+        self.wagers.insert(player.term_id, amount);
+        Ok(())
+    }
+
+    pub fn wagered_players(&self) -> Vec<u8> {
+        Self::sorted_keys(&self.wagers)
+    }
+
+    pub fn all_wagers_placed(&self) -> bool {
+        self.participants
+            .iter()
+            .all(|id| self.wagers.contains_key(id))
+    }
+
+    pub fn judge_answer(
+        &mut self,
+        player_id: u8,
+        answered_correctly: bool,
+    ) -> Result<(), GameplayError> {
+        if !self.is_participant(player_id) {
+            log::info!("Player {} doesn't participate in the final", player_id);
+            return Err(GameplayError::PlayerNotPresent(player_id.into()));
+        }
+
+        self.verdicts.insert(player_id, answered_correctly);
+        Ok(())
+    }
+
+    pub fn judged_players(&self) -> Vec<u8> {
+        Self::sorted_keys(&self.verdicts)
+    }
+
+    pub fn all_answers_judged(&self) -> bool {
+        self.participants
+            .iter()
+            .all(|id| self.verdicts.contains_key(id))
+    }
+
+    /// Wager and verdict of every participant
+    pub fn results(&self) -> Vec<(u8, i32, bool)> {
+        self.participants
+            .iter()
+            .map(|id| {
+                let wager = self.wagers.get(id).copied().unwrap_or_default();
+                let verdict = self.verdicts.get(id).copied().unwrap_or_default();
+                (*id, wager, verdict)
+            })
+            .collect()
+    }
+
+    /// Retired player leaves the final while the themes are being eliminated
+    pub fn remove_participant(&mut self, player_id: u8) -> Result<(), GameplayError> {
+        if self.is_theme_picked() {
+            log::info!(
+                "Final theme is picked. Player {} has to finish the final",
+                player_id
+            );
+            return Err(GameplayError::OperationForbidden);
+        }
+        self.participants.retain(|&id| id != player_id);
+        Ok(())
+    }
+
+    pub fn move_player(&mut self, player_id: u8, new_player_id: u8) {
+        for id in self.participants.iter_mut().filter(|id| **id == player_id) {
+            *id = new_player_id;
+        }
+        if let Some(wager) = self.wagers.remove(&player_id) {
+            self.wagers.insert(new_player_id, wager);
+        }
+        if let Some(verdict) = self.verdicts.remove(&player_id) {
+            self.verdicts.insert(new_player_id, verdict);
+        }
+    }
+
+    fn sorted_keys<V>(map: &HashMap<u8, V>) -> Vec<u8> {
+        let mut keys: Vec<u8> = map.keys().copied().collect();
+        keys.sort();
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::scored_players as players;
+
+    fn themes() -> Vec<String> {
+        vec![
+            "Music".to_string(),
+            "Movies".to_string(),
+            "Sports".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_players_without_score_are_out() {
+        let players = players(&[(1, 500), (2, 0), (3, -100), (4, 200)]);
+        let final_round = FinalRound::new(&players, themes());
+
+        assert_eq!(final_round.participants, vec![4, 1]);
+    }
+
+    #[test]
+    fn test_themes_are_eliminated_in_turns() {
+        let mut final_round = FinalRound::new(&players(&[(1, 500), (2, 300)]), themes());
+
+        assert_eq!(final_round.eliminator_id(), Some(2));
+        final_round.eliminate_theme("Music").expect("Test");
+        assert_eq!(final_round.eliminator_id(), Some(1));
+        assert!(final_round.eliminate_theme("Music").is_err());
+        final_round.eliminate_theme("Sports").expect("Test");
+        assert_eq!(final_round.eliminator_id(), None);
+        assert_eq!(final_round.final_theme(), Some(&"Movies".to_string()));
+    }
+
+    #[test]
+    fn test_wager_must_fit_score() {
+        let players = players(&[(1, 500), (2, 300)]);
+        let mut final_round = FinalRound::new(&players, themes());
+
+        assert!(final_round.place_wager(&players[&1], 0).is_err());
+        assert!(final_round.place_wager(&players[&2], 301).is_err());
+        final_round.place_wager(&players[&1], 500).expect("Test");
+        assert!(!final_round.all_wagers_placed());
+        final_round.place_wager(&players[&2], 300).expect("Test");
+        assert!(final_round.all_wagers_placed());
+        assert_eq!(final_round.wagered_players(), vec![1, 2]);
+    }
+}
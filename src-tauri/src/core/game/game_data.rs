@@ -1,38 +1,106 @@
-use crate::core::game_entities::{GamePackError, Player, PlayerState};
+use crate::core::game::auction::Auction;
+use crate::core::game::countdown::Countdown;
+use crate::core::game::false_start::{FalseStartRule, FalseStarts};
+use crate::core::game::final_round::FinalRound;
+use crate::core::game::player_profiles::GameResult;
+use crate::core::game::question_chooser::QuestionChooserPolicy;
+use crate::core::game::reaction_stats::AnswerRace;
+use crate::core::game::round_clock::RoundClock;
+use crate::core::game::scoring_rules::ScoringRules;
+use crate::core::game::team::{Team, TeamSetup};
+use crate::core::game_entities::{GamePackError, GameplayError, Player, PlayerState};
 use crate::core::game_pack::pack_content_entities::{PackContent, Question, Round, RoundStats};
-use crate::host_api::dto::{PlayerEndRoundStatsDto, QuestionDto, RoundStatsDto};
+use crate::host_api::dto::{PlayerEndRoundStatsDto, QuestionDto, QuestionType, RoundStatsDto};
 use crate::host_api::events::{
     emit_players, emit_players_by_players_map, emit_question, emit_round,
 };
-use crate::hub::hub_api::PlayerEvent;
+use crate::hub::hub_api::{PlayerEvent, TermButtonState};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct GameData {
-    /// Entities
+    /// Entities. Not saved with the game: it's restored from the pack and `RoundProgress`
+    #[serde(skip)]
     pack_content: PackContent,
     pub players: HashMap<u8, Player>,
+    /// Teams by the id of their entry in `players`. Empty for the solo game
+    pub teams: HashMap<u8, Team>,
     /// Game State
     current_round_index: Option<usize>,
     pub active_player_id: u8,
+    /// Player whose turn is to choose the question
+    pub question_chooser_id: Option<u8>,
+    /// The chooser got the turn because the previous one didn't choose the question in time
+    #[serde(default)]
+    pub chooser_picked_on_timeout: bool,
+    /// Player who answered the current question correctly
+    pub last_correct_answerer_id: Option<u8>,
+    /// Team member whose click gave the answer to the team
+    pub answering_member_id: Option<u8>,
     pub answer_allowed: bool,
     pub question_number: i32,
     /// Current question
     pub current_question: Question,
+    /// Bidding of the current 'auction' question
+    pub auction: Option<Auction>,
+    /// Theme elimination, wagers and verdicts of the 'final' round
+    pub final_round: Option<FinalRound>,
     /// Event frame. Flushed every new question
+    #[serde(skip)]
     pub events: Arc<RwLock<Vec<PlayerEvent>>>,
     pub allow_answer_timestamp: u32,
+    /// Presses since the answer was allowed. Restarted every time it's allowed
+    #[serde(skip)]
+    pub answer_race: AnswerRace,
+    /// Terminals locked out for pressing the button too early
+    pub false_starts: FalseStarts,
     pub round_duration_min: i32,
+    pub round_clock: RoundClock,
+    /// Buzz window or answer time limit of the current state. Restarted when the state is entered
+    #[serde(skip)]
+    pub countdown: Option<Countdown>,
     pub game_mode: GameMode,
 }
 
-#[derive(Debug, Default, Clone)]
+/// Part of the pack round changed by the game
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct RoundProgress {
+    /// Topics and prices of the used questions
+    pub used_questions: Vec<(String, i32)>,
+    pub questions_left: i32,
+    pub round_stats: RoundStats,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct GameMode {
     pub round_duration: Duration,
     pub question_chooser_answers_first: bool,
     pub pig_in_poke_enabled: bool,
+    /// Time to request the answer after it's allowed. Unlimited if not set
+    pub buzz_window: Option<Duration>,
+    /// Time for the answering player to answer. Unlimited if not set
+    pub answer_time_limit: Option<Duration>,
+    /// Judge the answer as wrong when the answer time limit expires
+    pub wrong_answer_on_timeout: bool,
+    /// Locks out players pressing the button right before the answer is allowed. Off if not set
+    pub false_start: Option<FalseStartRule>,
+    pub scoring_rules: ScoringRules,
+    pub question_chooser_policy: QuestionChooserPolicy,
+    /// Time to choose the question. Unlimited if not set
+    pub question_chooser_timeout: Option<Duration>,
+    /// Time to press for the first turn in the round. Unlimited if not set
+    pub first_chooser_timeout: Option<Duration>,
+    /// The first chooser of the round is picked randomly instead of the fastest press
+    pub random_first_chooser: bool,
+    /// Players are grouped into teams when the game starts. Solo game if empty
+    pub teams: Vec<TeamSetup>,
+    /// No buzzers: the host gives the answers and picks the first chooser. Clicks aren't polled
+    pub manual_buzzers: bool,
 }
 
 impl GameData {
@@ -81,6 +149,21 @@ impl GameData {
         Ok(())
     }
 
+    /// Topic and price of a random question not played in the current round
+    pub fn random_question(&self) -> Option<(String, i32)> {
+        let questions: Vec<(&String, i32)> = self
+            .current_round_ref()
+            .topics
+            .iter()
+            .flat_map(|(name, topic)| topic.questions.values().map(move |q| (name, q)))
+            .filter(|(_, q)| !q.is_used)
+            .map(|(name, q)| (name, q.price))
+            .collect();
+        questions
+            .choose(&mut thread_rng())
+            .map(|(name, price)| (name.to_string(), *price))
+    }
+
     pub fn get_question(&self, topic_name: &str, price: i32) -> Result<&Question, GamePackError> {
         let topic = self
             .current_round_ref()
@@ -147,6 +230,36 @@ impl GameData {
         &self.players
     }
 
+    /// Team members instead of their teams
+    pub fn individual_players(&self) -> Vec<&Player> {
+        self.players
+            .values()
+            .filter(|p| !self.teams.contains_key(&p.term_id))
+            .chain(self.teams.values().flat_map(|t| t.members.iter()))
+            .collect()
+    }
+
+    /// Team members share the win of their team
+    pub fn game_results(&self) -> Vec<GameResult> {
+        let best_score = self.players.values().map(|p| p.stats.score).max();
+        self.individual_players()
+            .into_iter()
+            .map(|p| {
+                let entry_id = self.team_id_of(p.term_id);
+                let entry_score = self.players.get(&entry_id).map(|entry| entry.stats.score);
+                GameResult {
+                    name: p.name.clone(),
+                    score: p.stats.score,
+                    team_id: self.teams.contains_key(&entry_id).then_some(entry_id),
+                    entry_score: entry_score.unwrap_or(p.stats.score),
+                    answered_correctly: p.stats.answered_correctly,
+                    answered_wrong: p.stats.answered_wrong,
+                    is_winner: entry_score.is_some() && entry_score == best_score,
+                }
+            })
+            .collect()
+    }
+
     pub fn players_ref_as_vec(&self) -> Vec<&Player> {
         log::debug!("Players: {:#?}", self.players);
         self.players.values().collect()
@@ -158,15 +271,33 @@ impl GameData {
             .expect("Expected to have current round index")]
     }
 
+    pub fn current_round_index(&self) -> Option<usize> {
+        self.current_round_index
+    }
+
     pub fn current_round_opt_ref(&self) -> Option<&Round> {
         self.current_round_index
             .map(|i| &self.pack_content.rounds[i])
     }
 
+    pub fn final_round_mut(&mut self) -> Result<&mut FinalRound, GameplayError> {
+        self.final_round
+            .as_mut()
+            .ok_or(GameplayError::OperationForbidden)
+    }
+
     pub fn current_question_ref(&self) -> &Question {
         &self.current_question
     }
 
+    /// Price the current question is played for. For 'auction' questions it's the winning stake
+    pub fn current_question_price(&self) -> i32 {
+        self.auction
+            .as_ref()
+            .map(Auction::stake)
+            .unwrap_or(self.current_question.price)
+    }
+
     /// Mutable player operations (used for player monitoring by hub)
     pub fn erase_players(&mut self) {
         self.players = HashMap::default();
@@ -190,6 +321,35 @@ impl GameData {
         emit_players_by_players_map(&self.players);
     }
 
+    pub fn ensure_can_choose_question(&self, player_id: u8) -> Result<(), GameplayError> {
+        let player = self
+            .players
+            .get(&player_id)
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
+        if player.state == PlayerState::Dead {
+            log::info!("Player {} is out of the game and can't choose", player_id);
+            return Err(GameplayError::OperationForbidden);
+        }
+        Ok(())
+    }
+
+    /// Gives the turn to choose the question to the player
+    pub fn set_question_chooser(&mut self, term_id: u8) {
+        let previous = self
+            .question_chooser_id
+            .and_then(|id| self.players.get_mut(&id));
+        if let Some(previous) = previous {
+            if previous.state == PlayerState::QuestionChooser {
+                previous.state = PlayerState::Idle;
+            }
+        }
+
+        self.set_active_player_by_id(term_id);
+        self.question_chooser_id = Some(term_id);
+        self.chooser_picked_on_timeout = false;
+        self.set_active_player_state(PlayerState::QuestionChooser);
+    }
+
     pub fn set_active_player_id(&mut self, term_id: u8) {
         self.active_player_id = term_id;
     }
@@ -197,11 +357,18 @@ impl GameData {
     pub fn set_current_question(&mut self, question: Question) {
         self.question_number += 1;
         self.current_question = question;
+        self.last_correct_answerer_id = None;
+        self.auction = None;
         let mut dto: QuestionDto = (&self.current_question).into();
         dto.number = self.question_number;
         emit_question(dto);
     }
 
+    pub fn peek_events(&self) -> Vec<PlayerEvent> {
+        let guard = self.events.read().expect("Expected to get events reader");
+        guard.clone()
+    }
+
     pub fn take_events(&self) -> Vec<PlayerEvent> {
         let mut guard = self.events.write().expect("Expected to get events reader");
         let events_batch = guard.clone();
@@ -209,10 +376,124 @@ impl GameData {
         events_batch
     }
 
+    /// Groups players into the teams requested by the game mode
+    pub fn form_teams(&mut self) -> Result<(), GameplayError> {
+        for setup in self.game_mode.teams.clone() {
+            let is_taken = |id: &u8| self.teams.values().any(|t| t.has_member(*id));
+            if let Some(id) = setup.member_ids.iter().find(|id| is_taken(id)) {
+                log::info!("Player {} is already in a team", id);
+                return Err(GameplayError::InvalidTeam);
+            }
+
+            let team = Team::form(&setup, &mut self.players)?;
+            log::info!("Team '{}' is formed of {:?}", team.name, setup.member_ids);
+            self.teams.insert(team.id, team);
+        }
+        Ok(())
+    }
+
+    /// Id of the `players` entry the terminal plays for
+    pub fn team_id_of(&self, term_id: u8) -> u8 {
+        self.teams
+            .values()
+            .find(|t| t.has_member(term_id))
+            .map(|t| t.id)
+            .unwrap_or(term_id)
+    }
+
+    /// Counts the press to the race if it's the first one of the terminal
+    pub fn register_press(&mut self, term_id: u8, timestamp: u32) {
+        if let Some((reaction_ms, won_race)) = self.answer_race.register(term_id, timestamp) {
+            log::debug!("Player {} reacted in {} ms", term_id, reaction_ms);
+            self.record_reaction(term_id, reaction_ms, won_race);
+        }
+    }
+
+    /// Presses coming after the answer was given to the fastest player still lose the race
+    pub fn register_late_presses(&mut self, events: &[PlayerEvent]) {
+        let mut presses: Vec<&PlayerEvent> = events
+            .iter()
+            .filter(|e| e.state == TermButtonState::Pressed)
+            .filter(|e| !self.false_starts.is_locked(e.term_id, e.timestamp))
+            .filter(|e| {
+                let id = self.team_id_of(e.term_id);
+                matches!(self.players.get(&id), Some(p) if p.allowed_to_click())
+            })
+            .collect();
+        presses.sort_by_key(|e| e.timestamp);
+        presses
+            .into_iter()
+            .for_each(|e| self.register_press(e.term_id, e.timestamp));
+    }
+
+    /// Reaction is counted for the team and for its member who pressed
+    pub fn record_reaction(&mut self, term_id: u8, reaction_ms: u32, won_race: bool) {
+        let id = self.team_id_of(term_id);
+        if let Some(player) = self.players.get_mut(&id) {
+            player.stats.reactions.record(reaction_ms, won_race);
+        }
+        if let Some(member) = self.teams.get_mut(&id).and_then(|t| t.member_mut(term_id)) {
+            member.stats.reactions.record(reaction_ms, won_race);
+        }
+    }
+
+    pub fn start_round_reactions(&mut self) {
+        let members = self.teams.values_mut().flat_map(|t| t.members.iter_mut());
+        self.players
+            .values_mut()
+            .chain(members)
+            .for_each(|p| p.stats.reactions.start_round());
+    }
+
+    pub fn team_members(&self, id: u8) -> &[Player] {
+        self.teams
+            .get(&id)
+            .map(|t| t.members.as_slice())
+            .unwrap_or_default()
+    }
+
     pub fn set_pack_content(&mut self, pack_content: PackContent) {
         self.pack_content = pack_content;
     }
 
+    /// Progress of every round of the pack. Saved instead of the whole pack content
+    pub fn pack_progress(&self) -> Vec<RoundProgress> {
+        self.pack_content
+            .rounds
+            .iter()
+            .map(|round| RoundProgress {
+                used_questions: round
+                    .topics
+                    .values()
+                    .flat_map(|topic| topic.questions.values())
+                    .filter(|q| q.is_used)
+                    .map(|q| (q.topic.clone(), q.price))
+                    .collect(),
+                questions_left: round.questions_left,
+                round_stats: round.round_stats.clone(),
+            })
+            .collect()
+    }
+
+    /// Puts the pack content back into the saved game and applies the progress of its rounds
+    pub fn restore_pack_content(&mut self, pack_content: PackContent, progress: &[RoundProgress]) {
+        self.pack_content = pack_content;
+        for (round, progress) in self.pack_content.rounds.iter_mut().zip(progress) {
+            for (topic, price) in &progress.used_questions {
+                let question = round
+                    .topics
+                    .get_mut(topic)
+                    .and_then(|t| t.questions.get_mut(price));
+                match question {
+                    Some(question) => question.is_used = true,
+                    None => log::warn!("Used question '{}' for {} isn't in the pack", topic, price),
+                }
+            }
+            round.questions_left = progress.questions_left;
+            round.round_stats = progress.round_stats.clone();
+        }
+    }
+
     pub fn current_round_stats_mut(&mut self) -> &mut RoundStats {
         &mut self.current_round_mut().round_stats
     }
@@ -225,7 +506,20 @@ impl GameData {
             topic,
             price
         );
-        self.use_question(topic, price)
+        self.use_question(topic, price)?;
+        self.count_played_question();
+        Ok(())
+    }
+
+    fn count_played_question(&mut self) {
+        let question_type = self.current_question.question_type.clone();
+        let stats = self.current_round_stats_mut();
+        stats.questions_played += 1;
+        match question_type {
+            QuestionType::PigInPoke => stats.pip_questions_played += 1,
+            QuestionType::Auction => stats.auction_questions_played += 1,
+            QuestionType::Normal => stats.normal_questions_played += 1,
+        }
     }
 
     pub fn to_round_stats_dto(&self) -> RoundStatsDto {
@@ -235,21 +529,21 @@ impl GameData {
             questionsPlayed: self.current_round_ref().question_count,
             normalQuestionsPlayed: stats.normal_questions_played,
             pigInPokeQuestionPlayed: stats.pip_questions_played,
+            auctionQuestionPlayed: stats.auction_questions_played,
             totalCorrectAnswers: stats.total_correct_answers,
             totalWrongAnswers: stats.total_wrong_answers,
             totalTries: stats.total_tries,
-            roundTimeSec: 666,
+            roundTimeSec: self.round_clock.elapsed().as_secs() as i32,
             players: self
                 .players
                 .values()
                 .map(|p| PlayerEndRoundStatsDto {
-                    id: p.term_id as i32,
-                    name: p.name.clone(),
-                    score: p.stats.score,
-                    playerIconPath: p.icon.clone(),
-                    totalAnswers: p.stats.total_tries,
-                    answeredCorrectly: p.stats.answered_correctly,
-                    answeredWrong: p.stats.answered_wrong,
+                    members: self
+                        .team_members(p.term_id)
+                        .iter()
+                        .map(Into::into)
+                        .collect(),
+                    ..p.into()
                 })
                 .collect(),
         }
@@ -264,7 +558,7 @@ impl GameData {
         self.active_player_id = player.term_id;
     }
 
-    fn player_by_id_mut(&mut self, term_id: &u8) -> &mut Player {
+    pub(in crate::core) fn player_by_id_mut(&mut self, term_id: &u8) -> &mut Player {
         let msg = format!(
             "Expected to have term_id: {} in players map: {:?}",
             term_id, self.players
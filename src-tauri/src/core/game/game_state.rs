@@ -1,12 +1,13 @@
 use crate::core::game::ctx::game_ctx::GameCtx;
 use crate::core::game::game_data::GameData;
 use crate::core::game::state_structs::{
-    AnswerAttemptReceived, CheckEndOfRound, ChooseQuestion, DisplayQuestion, EndQuestion,
-    EndTheGame, PickFirstQuestionChooser, SetupAndLoading, ShowRoundStats, StartNextRound,
+    AnswerAttemptReceived, AnswerFinalQuestion, AuctionBidding, CheckEndOfRound, ChooseQuestion,
+    DisplayQuestion, EliminateFinalThemes, EndQuestion, EndTheGame, PickFirstQuestionChooser,
+    PickPipVictim, PlaceFinalWagers, SetupAndLoading, ShowRoundStats, StartNextRound,
     WaitingForAnswerRequests,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum GameState {
     /// Configuring players and game pack.
     /// Next state: `PickFirstQuestionChooser` (when game started)
@@ -18,14 +19,27 @@ pub enum GameState {
 
     /// The state where the question chooser selects a question.
     /// Next state: `DisplayQuestion` (when question is selected)
+    ///         or: `PickPipVictim` (when 'pig in poke' question is selected)
+    ///         or: `AuctionBidding` (when 'auction' question is selected)
     ChooseQuestion(GameCtx<ChooseQuestion>),
 
+    /// The question chooser selected 'pig in poke' question and has to give it away to another player.
+    /// Next state: `AnswerAttemptReceived` (when the victim is picked. The victim answers alone)
+    PickPipVictim(GameCtx<PickPipVictim>),
+
+    /// The question chooser selected 'auction' question. Players bid up to their score, pass or go all-in.
+    /// Next state: `AnswerAttemptReceived` (when the auction is finished. The winner answers alone for the stake)
+    AuctionBidding(GameCtx<AuctionBidding>),
+
     /// When the question is selected, everyone reads it but can't answer until the host allows.
     /// Next state: `WaitingForAnswerRequests` (when host presses 'Allow answer' button)
     DisplayQuestion(GameCtx<DisplayQuestion>),
 
     /// The host allowed answering the question, and now players can send answer requests.
-    /// Next state: `AnswerAttemptReceived` (when the first answer request is received)
+    /// Answer requests are processed as hub events arrive, so the host isn't blocked while waiting.
+    /// Next state: `AnswerAttemptReceived` (when the first answer request is received or the host picks the player)
+    ///         or: `DisplayQuestion` (when the host cancels waiting)
+    ///         or: `EndQuestion` (when the buzz window expires or the host stops asking)
     WaitingForAnswerRequests(GameCtx<WaitingForAnswerRequests>),
 
     /// The quickest player pressed the 'Answer' button first, and now they have the right to try answering the question.
@@ -40,7 +54,7 @@ pub enum GameState {
     EndQuestion(GameCtx<EndQuestion>),
 
     /// Check if the round is over. If all questions in the round are answered, proceed to round-end actions.
-    /// Next state: `CalcRoundStats` (when the round is over)
+    /// Next state: `ShowRoundStats` (when the round is over)
     ///         or: `ChooseQuestion` (when the round is continuing)
     CheckEndOfRound(GameCtx<CheckEndOfRound>),
 
@@ -51,8 +65,22 @@ pub enum GameState {
 
     /// Start the next round by resetting game state and proceeding to question selection.
     /// Next state: `ChooseQuestion` (when the first question of the new round is picked)
+    ///         or: `EliminateFinalThemes` (when the new round is the 'final' one)
+    ///         or: `ShowRoundStats` (when nobody has positive score to play the 'final' round)
     StartNextRound(GameCtx<StartNextRound>),
 
+    /// Players with positive score take turns eliminating themes of the 'final' round.
+    /// Next state: `PlaceFinalWagers` (when only one theme is left)
+    EliminateFinalThemes(GameCtx<EliminateFinalThemes>),
+
+    /// Every final participant places a hidden wager up to their score.
+    /// Next state: `AnswerFinalQuestion` (when all wagers are placed)
+    PlaceFinalWagers(GameCtx<PlaceFinalWagers>),
+
+    /// The final question is displayed. The host judges the answer of every participant.
+    /// Next state: `ShowRoundStats` (when all answers are judged and scores are settled)
+    AnswerFinalQuestion(GameCtx<AnswerFinalQuestion>),
+
     /// The game is over, and the final results are displayed.
     EndTheGame(GameCtx<EndTheGame>),
 }
@@ -66,11 +94,30 @@ impl GameState {
         )
     }
 
+    /// Round time isn't counted while the host judges answers or between rounds
+    pub fn is_round_clock_running(&self) -> bool {
+        matches!(
+            self,
+            GameState::PickFirstQuestionChooser(_)
+                | GameState::ChooseQuestion(_)
+                | GameState::PickPipVictim(_)
+                | GameState::AuctionBidding(_)
+                | GameState::DisplayQuestion(_)
+                | GameState::WaitingForAnswerRequests(_)
+                | GameState::EndQuestion(_)
+                | GameState::CheckEndOfRound(_)
+                | GameState::EliminateFinalThemes(_)
+                | GameState::PlaceFinalWagers(_)
+        )
+    }
+
     pub fn game_mut(&mut self) -> &mut GameData {
         match self {
             GameState::SetupAndLoading(game_ctx) => game_ctx.game_mut(),
             GameState::PickFirstQuestionChooser(game_ctx) => game_ctx.game_mut(),
             GameState::ChooseQuestion(game_ctx) => game_ctx.game_mut(),
+            GameState::PickPipVictim(game_ctx) => game_ctx.game_mut(),
+            GameState::AuctionBidding(game_ctx) => game_ctx.game_mut(),
             GameState::DisplayQuestion(game_ctx) => game_ctx.game_mut(),
             GameState::WaitingForAnswerRequests(game_ctx) => game_ctx.game_mut(),
             GameState::AnswerAttemptReceived(game_ctx) => game_ctx.game_mut(),
@@ -78,6 +125,9 @@ impl GameState {
             GameState::CheckEndOfRound(game_ctx) => game_ctx.game_mut(),
             GameState::ShowRoundStats(game) => game.game_mut(),
             GameState::StartNextRound(game) => game.game_mut(),
+            GameState::EliminateFinalThemes(game) => game.game_mut(),
+            GameState::PlaceFinalWagers(game) => game.game_mut(),
+            GameState::AnswerFinalQuestion(game) => game.game_mut(),
             GameState::EndTheGame(game) => game.game_mut(),
         }
     }
@@ -87,6 +137,8 @@ impl GameState {
             GameState::SetupAndLoading(game_ctx) => game_ctx.game_ref(),
             GameState::PickFirstQuestionChooser(game_ctx) => game_ctx.game_ref(),
             GameState::ChooseQuestion(game_ctx) => game_ctx.game_ref(),
+            GameState::PickPipVictim(game_ctx) => game_ctx.game_ref(),
+            GameState::AuctionBidding(game_ctx) => game_ctx.game_ref(),
             GameState::DisplayQuestion(game_ctx) => game_ctx.game_ref(),
             GameState::WaitingForAnswerRequests(game_ctx) => game_ctx.game_ref(),
             GameState::AnswerAttemptReceived(game_ctx) => game_ctx.game_ref(),
@@ -94,6 +146,9 @@ impl GameState {
             GameState::CheckEndOfRound(game_ctx) => game_ctx.game_ref(),
             GameState::ShowRoundStats(game) => game.game_ref(),
             GameState::StartNextRound(game) => game.game_ref(),
+            GameState::EliminateFinalThemes(game) => game.game_ref(),
+            GameState::PlaceFinalWagers(game) => game.game_ref(),
+            GameState::AnswerFinalQuestion(game) => game.game_ref(),
             GameState::EndTheGame(game) => game.game_ref(),
         }
     }
@@ -103,6 +158,8 @@ impl GameState {
             GameState::SetupAndLoading(_) => "SetupAndLoading",
             GameState::PickFirstQuestionChooser(_) => "PickFirstQuestionChooser",
             GameState::ChooseQuestion(_) => "ChooseQuestion",
+            GameState::PickPipVictim(_) => "PickPipVictim",
+            GameState::AuctionBidding(_) => "AuctionBidding",
             GameState::DisplayQuestion(_) => "DisplayQuestion",
             GameState::WaitingForAnswerRequests(_) => "WaitingForAnswerRequests",
             GameState::AnswerAttemptReceived(_) => "AnswerAttemptReceived",
@@ -110,26 +167,36 @@ impl GameState {
             GameState::CheckEndOfRound(_) => "CheckEndOfRound",
             GameState::ShowRoundStats(_) => "ShowRoundStats",
             GameState::StartNextRound(_) => "StartNextRound",
+            GameState::EliminateFinalThemes(_) => "EliminateFinalThemes",
+            GameState::PlaceFinalWagers(_) => "PlaceFinalWagers",
+            GameState::AnswerFinalQuestion(_) => "AnswerFinalQuestion",
             GameState::EndTheGame(_) => "EndTheGame",
         }
     }
 
-    pub fn from_name_and_game(name: &str, game: GameData) -> GameState {
+    /// State of the given name with the game. `None` if there is no such state
+    pub fn from_name_and_game(name: &str, game: GameData) -> Option<GameState> {
         let context: GameCtx<SetupAndLoading> = GameCtx::<SetupAndLoading>::new_with_game(game);
-        match name {
+        let state = match name {
             "SetupAndLoading" => GameState::SetupAndLoading(context.transition()),
             "PickFirstQuestionChooser" => GameState::PickFirstQuestionChooser(context.transition()),
             "ChooseQuestion" => GameState::ChooseQuestion(context.transition()),
+            "PickPipVictim" => GameState::PickPipVictim(context.transition()),
+            "AuctionBidding" => GameState::AuctionBidding(context.transition()),
             "DisplayQuestion" => GameState::DisplayQuestion(context.transition()),
             "WaitingForAnswerRequests" => GameState::WaitingForAnswerRequests(context.transition()),
             "AnswerAttemptReceived" => GameState::AnswerAttemptReceived(context.transition()),
             "EndQuestion" => GameState::EndQuestion(context.transition()),
             "CheckEndOfRound" => GameState::CheckEndOfRound(context.transition()),
-            "CalcRoundStats" => GameState::ShowRoundStats(context.transition()),
+            "ShowRoundStats" => GameState::ShowRoundStats(context.transition()),
             "StartNextRound" => GameState::StartNextRound(context.transition()),
+            "EliminateFinalThemes" => GameState::EliminateFinalThemes(context.transition()),
+            "PlaceFinalWagers" => GameState::PlaceFinalWagers(context.transition()),
+            "AnswerFinalQuestion" => GameState::AnswerFinalQuestion(context.transition()),
             "EndTheGame" => GameState::EndTheGame(context.transition()),
-            &_ => panic!("Invalid state name {}", name),
-        }
+            _ => return None,
+        };
+        Some(state)
     }
 }
 
@@ -0,0 +1,418 @@
+use crate::core::game::countdown::CountdownKind;
+use crate::core::game::game_data::{GameData, GameMode};
+use crate::core::game::game_state::GameState;
+use crate::core::game_entities::Player;
+use crate::host_api::event_sink::EventSink;
+use crate::hub::hub_api::{PlayerEvent, TermButtonState};
+use error_stack::{IntoReport, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::api::path::home_dir;
+
+pub static JOURNAL_DIR_NAME: &str = "journal";
+
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+pub enum JournalError {
+    #[error("Can't write game journal")]
+    WriteFailed,
+    #[error("Can't read game journal")]
+    ReadFailed,
+    #[error("Game journal is corrupted")]
+    Corrupted,
+}
+
+/// Host decision which changes the game. Recorded with its arguments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum HostCommand {
+    StartNewGame {
+        game_mode: GameMode,
+    },
+    SelectQuestion {
+        topic: String,
+        price: i32,
+    },
+    AllowAnswer,
+    RequestAnswer {
+        player_id: u8,
+    },
+    CancelAnswerRequests,
+    AnswerQuestion {
+        answered_correctly: bool,
+    },
+    StopAskingAndShowAnswer,
+    FinishQuestion,
+    InitNextRound,
+    SetQuestionChooser {
+        player_id: u8,
+    },
+    PickRandomQuestionChooser,
+    SendPipVictim {
+        victim_id: u8,
+    },
+    PlaceAuctionBid {
+        player_id: u8,
+        amount: i32,
+    },
+    PlaceAuctionAllIn {
+        player_id: u8,
+    },
+    PassAuction {
+        player_id: u8,
+    },
+    FinishAuction,
+    EliminateFinalTheme {
+        theme: String,
+    },
+    PlaceFinalWager {
+        player_id: u8,
+        amount: i32,
+    },
+    JudgeFinalAnswer {
+        player_id: u8,
+        answered_correctly: bool,
+    },
+    EditPlayerScore {
+        player_id: u8,
+        score: i32,
+    },
+    AddPlayer {
+        player_id: u8,
+        name: String,
+    },
+    RetirePlayer {
+        player_id: u8,
+    },
+    MovePlayer {
+        player_id: u8,
+        new_player_id: u8,
+    },
+    UndoLastAction,
+    FinishGame,
+}
+
+impl HostCommand {
+    /// Commands which start, finish or undo the game aren't undone themselves
+    pub fn is_undoable(&self) -> bool {
+        !matches!(
+            self,
+            HostCommand::StartNewGame { .. }
+                | HostCommand::UndoLastAction
+                | HostCommand::FinishGame
+        )
+    }
+
+    /// Player the command is explicitly about
+    pub fn player_id(&self) -> Option<u8> {
+        match self {
+            HostCommand::RequestAnswer { player_id }
+            | HostCommand::SetQuestionChooser { player_id }
+            | HostCommand::PlaceAuctionBid { player_id, .. }
+            | HostCommand::PlaceAuctionAllIn { player_id }
+            | HostCommand::PassAuction { player_id }
+            | HostCommand::PlaceFinalWager { player_id, .. }
+            | HostCommand::JudgeFinalAnswer { player_id, .. }
+            | HostCommand::EditPlayerScore { player_id, .. }
+            | HostCommand::AddPlayer { player_id, .. }
+            | HostCommand::RetirePlayer { player_id }
+            | HostCommand::MovePlayer { player_id, .. } => Some(*player_id),
+            HostCommand::SendPipVictim { victim_id } => Some(*victim_id),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum JournalAction {
+    /// Pack and players the game was started with
+    GameStarted {
+        pack_path: String,
+        pack_hash: String,
+        players: Vec<Player>,
+    },
+    /// Host command which succeeded
+    HostCommand(HostCommand),
+    /// Button press received from the hub. Timestamps are the ones of the hub
+    Buzz {
+        term_id: u8,
+        timestamp: u32,
+        answer_allowed_at: u32,
+    },
+    /// Countdown expired and the game reacted on it
+    Timeout(CountdownKind),
+    /// Game picked the first chooser of the round randomly. Recorded before the host command
+    /// which started the round
+    RandomChooserPicked { player_id: u8 },
+    /// Game state changed
+    Transition,
+    /// Scores the game ended with
+    GameEnded { scores: BTreeMap<u8, i32> },
+}
+
+/// Presses of the hub batch in the order they are journaled and replayed one by one.
+/// The hub doesn't read the terminals in the order they were pressed, so the batch is sorted
+/// by the press time. Otherwise the replay gives the answer to the first press of the batch
+pub fn buzzes_of(events: &[PlayerEvent], answer_allowed_at: u32) -> Vec<JournalAction> {
+    let mut presses: Vec<&PlayerEvent> = events
+        .iter()
+        .filter(|e| e.state == TermButtonState::Pressed)
+        .collect();
+    presses.sort_by_key(|e| e.timestamp);
+    presses
+        .into_iter()
+        .map(|e| JournalAction::Buzz {
+            term_id: e.term_id,
+            timestamp: e.timestamp,
+            answer_allowed_at,
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct QuestionRef {
+    pub topic: String,
+    pub price: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    /// Milliseconds since the UNIX epoch
+    pub timestamp: u64,
+    pub action: JournalAction,
+    pub state_before: String,
+    pub state_after: String,
+    pub player_id: Option<u8>,
+    pub question: Option<QuestionRef>,
+    /// Score change of the player caused by the action
+    pub score_delta: i32,
+}
+
+/// Part of the game described by the journal entry
+#[derive(Debug, Clone)]
+pub struct JournalSnapshot {
+    state_name: String,
+    scores: BTreeMap<u8, i32>,
+    active_player_id: u8,
+    question: Option<QuestionRef>,
+}
+
+impl JournalSnapshot {
+    pub fn of(state: &GameState) -> Self {
+        let data: &GameData = state.game_ctx_ref();
+        let question = &data.current_question;
+        Self {
+            state_name: state.name().to_owned(),
+            scores: Self::scores(data),
+            active_player_id: data.active_player_id,
+            question: (!question.topic.is_empty()).then(|| QuestionRef {
+                topic: question.topic.clone(),
+                price: question.price,
+            }),
+        }
+    }
+
+    pub fn scores(data: &GameData) -> BTreeMap<u8, i32> {
+        data.players
+            .iter()
+            .map(|(id, p)| (*id, p.stats.score))
+            .collect()
+    }
+
+    fn score_of(&self, player_id: u8) -> i32 {
+        self.scores.get(&player_id).copied().unwrap_or_default()
+    }
+}
+
+impl JournalEntry {
+    /// The entry is about the player named by the action, otherwise the one whose score changed,
+    /// otherwise the active one
+    pub fn new(action: JournalAction, before: &JournalSnapshot, after: &JournalSnapshot) -> Self {
+        let explicit_id = match &action {
+            JournalAction::HostCommand(command) => command.player_id(),
+            JournalAction::Buzz { term_id, .. } => Some(*term_id),
+            JournalAction::RandomChooserPicked { player_id } => Some(*player_id),
+            _ => None,
+        };
+        let scored_id = after
+            .scores
+            .keys()
+            .copied()
+            .find(|id| after.score_of(*id) != before.score_of(*id));
+        let active_id = Some(after.active_player_id).filter(|id| after.scores.contains_key(id));
+        let player_id = explicit_id.or(scored_id).or(active_id);
+
+        Self {
+            timestamp: now_ms(),
+            action,
+            state_before: before.state_name.clone(),
+            state_after: after.state_name.clone(),
+            player_id,
+            question: after.question.clone().or_else(|| before.question.clone()),
+            score_delta: player_id
+                .map(|id| after.score_of(id) - before.score_of(id))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Event emitted by the game while the journal was subscribed to the events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmittedEvent {
+    /// Milliseconds since the UNIX epoch
+    pub timestamp: u64,
+    pub event: String,
+    pub payload: Value,
+}
+
+/// Append-only JSON lines file with everything that happened during one game
+#[derive(Debug, Clone)]
+pub struct GameJournal {
+    path: PathBuf,
+}
+
+impl GameJournal {
+    /// Journal of the new game. Named by its start time
+    pub fn create() -> Self {
+        let file_name = format!("game_{}.jsonl", now_ms());
+        Self::open(journal_dir().join(file_name))
+    }
+
+    /// Continues the journal of the resumed game
+    pub fn open(path: PathBuf) -> Self {
+        log::info!("Game journal: {}", path.display());
+        Self { path }
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Events are kept aside of the entries, so the replay reads only what it replays
+    pub fn events_path(&self) -> PathBuf {
+        self.path.with_extension("events.jsonl")
+    }
+
+    pub fn append(&self, entry: &JournalEntry) -> Result<(), JournalError> {
+        append_json_line(&self.path, entry)
+    }
+
+    pub fn read_entries(&self) -> Result<Vec<JournalEntry>, JournalError> {
+        let jsonl = fs::read_to_string(&self.path)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't read {}", self.path.display()))
+            .change_context(JournalError::ReadFailed)?;
+
+        jsonl
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(index, line)| {
+                serde_json::from_str(line)
+                    .into_report()
+                    .attach_printable_lazy(|| format!("Bad entry #{}", index + 1))
+                    .change_context(JournalError::Corrupted)
+            })
+            .collect()
+    }
+}
+
+/// Journal subscribed as the event sink records everything the players and the host were shown
+impl EventSink for GameJournal {
+    fn emit(&self, event: &str, payload: &Value) {
+        let emitted = EmittedEvent {
+            timestamp: now_ms(),
+            event: event.to_string(),
+            payload: payload.clone(),
+        };
+        if let Err(e) = append_json_line(&self.events_path(), &emitted) {
+            log::error!("Can't journal event {}: {:?}", event, e);
+        }
+    }
+}
+
+fn append_json_line<T: Serialize>(path: &Path, value: &T) -> Result<(), JournalError> {
+    let mut line = serde_json::to_string(value)
+        .into_report()
+        .change_context(JournalError::WriteFailed)?;
+    line.push('\n');
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .into_report()
+            .change_context(JournalError::WriteFailed)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .into_report()
+        .attach_printable_lazy(|| format!("Can't append to {}", path.display()))
+        .change_context(JournalError::WriteFailed)
+}
+
+fn journal_dir() -> PathBuf {
+    let home = home_dir().expect("Expected home directory");
+    home.join("svoyak").join(JOURNAL_DIR_NAME)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(state_name: &str, scores: &[(u8, i32)], active_player_id: u8) -> JournalSnapshot {
+        JournalSnapshot {
+            state_name: state_name.to_string(),
+            scores: scores.iter().copied().collect(),
+            active_player_id,
+            question: Some(QuestionRef {
+                topic: "Music".to_string(),
+                price: 200,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_entry_names_player_whose_score_changed() {
+        let before = snapshot("AnswerAttemptReceived", &[(1, 0), (2, 100)], 1);
+        let after = snapshot("ShowAnswer", &[(1, 0), (2, 300)], 1);
+        let action = JournalAction::HostCommand(HostCommand::AnswerQuestion {
+            answered_correctly: true,
+        });
+
+        let entry = JournalEntry::new(action, &before, &after);
+        assert_eq!(entry.player_id, Some(2));
+        assert_eq!(entry.score_delta, 200);
+        assert_eq!(entry.state_before, "AnswerAttemptReceived");
+        assert_eq!(entry.state_after, "ShowAnswer");
+    }
+
+    #[test]
+    fn test_entry_prefers_player_of_the_command() {
+        let before = snapshot("PickPipVictim", &[(1, 100), (2, 100)], 1);
+        let after = snapshot("AnswerAttemptReceived", &[(1, 100), (2, 100)], 2);
+        let action = JournalAction::HostCommand(HostCommand::SendPipVictim { victim_id: 2 });
+
+        let entry = JournalEntry::new(action, &before, &after);
+        assert_eq!(entry.player_id, Some(2));
+        assert_eq!(entry.score_delta, 0);
+        assert_eq!(
+            entry.question,
+            Some(QuestionRef {
+                topic: "Music".to_string(),
+                price: 200
+            })
+        );
+    }
+}
@@ -1,4 +1,22 @@
+pub mod auction;
+pub mod clock;
+pub mod countdown;
 pub mod ctx;
+pub mod false_start;
+pub mod final_round;
 pub mod game_data;
 pub mod game_state;
+pub mod journal;
+pub mod player_profiles;
+pub mod question_chooser;
+pub mod reaction_stats;
+pub mod replay;
+pub mod roster;
+pub mod round_clock;
+pub mod saved_game;
+pub mod scoring_rules;
 pub mod state_structs;
+pub mod team;
+#[cfg(test)]
+pub mod test_fixtures;
+pub mod tournament;
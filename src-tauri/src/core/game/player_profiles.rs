@@ -0,0 +1,249 @@
+use error_stack::{IntoReport, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use tauri::api::path::home_dir;
+
+pub static PROFILES_FILE_NAME: &str = "player_profiles.json";
+
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+pub enum ProfileStoreError {
+    #[error("Can't read player profiles")]
+    ReadFailed,
+    #[error("Can't write player profiles")]
+    WriteFailed,
+    #[error("Player profiles are corrupted")]
+    Corrupted,
+}
+
+/// Accumulated results of all the games played
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlayerHistory {
+    pub games_played: u32,
+    pub wins: u32,
+    pub total_score: i64,
+    pub answered_correctly: u32,
+    pub answered_wrong: u32,
+}
+
+impl PlayerHistory {
+    /// Share of correct answers. Zero if the player never answered
+    pub fn accuracy(&self) -> f32 {
+        let answers = self.answered_correctly + self.answered_wrong;
+        if answers == 0 {
+            return 0.0;
+        }
+        self.answered_correctly as f32 / answers as f32
+    }
+}
+
+/// Regular player. Identified by the name
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub name: String,
+    pub icon: String,
+    /// Terminal the player usually takes. The player gets the profile name and icon there
+    pub preferred_term_id: Option<u8>,
+    pub history: PlayerHistory,
+}
+
+/// Result of the player in the finished game
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GameResult {
+    pub name: String,
+    pub score: i32,
+    /// Team the player played for. Members of a team take one place
+    pub team_id: Option<u8>,
+    /// Score of the team for its members, own score for the solo player. Places are given by it
+    pub entry_score: i32,
+    pub answered_correctly: i32,
+    pub answered_wrong: i32,
+    pub is_winner: bool,
+}
+
+/// Profiles kept between the sessions in the file under the app directory
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ProfileStore {
+    profiles: Vec<PlayerProfile>,
+}
+
+impl ProfileStore {
+    /// Empty store if nothing was saved yet
+    pub fn read() -> Result<Self, ProfileStoreError> {
+        let path = profiles_path();
+        let json = match fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e)
+                    .into_report()
+                    .attach_printable_lazy(|| format!("Can't read {}", path.display()))
+                    .change_context(ProfileStoreError::ReadFailed)
+            }
+        };
+
+        serde_json::from_str(&json)
+            .into_report()
+            .change_context(ProfileStoreError::Corrupted)
+    }
+
+    pub fn write(&self) -> Result<(), ProfileStoreError> {
+        let path = profiles_path();
+        let json = serde_json::to_string_pretty(self)
+            .into_report()
+            .change_context(ProfileStoreError::WriteFailed)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .into_report()
+                .change_context(ProfileStoreError::WriteFailed)?;
+        }
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, json)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't write {}", tmp_path.display()))
+            .change_context(ProfileStoreError::WriteFailed)?;
+        fs::rename(&tmp_path, &path)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't replace {}", path.display()))
+            .change_context(ProfileStoreError::WriteFailed)
+    }
+
+    /// Creates the profile or updates its icon and terminal keeping the history.
+    /// The terminal is released by the profile which preferred it before
+    pub fn save_profile(&mut self, name: &str, icon: &str, preferred_term_id: Option<u8>) {
+        if preferred_term_id.is_some() {
+            self.profiles
+                .iter_mut()
+                .filter(|p| p.preferred_term_id == preferred_term_id)
+                .for_each(|p| p.preferred_term_id = None);
+        }
+
+        match self.profile_mut(name) {
+            Some(profile) => {
+                profile.icon = icon.to_string();
+                profile.preferred_term_id = preferred_term_id;
+            }
+            None => self.profiles.push(PlayerProfile {
+                name: name.to_string(),
+                icon: icon.to_string(),
+                preferred_term_id,
+                history: PlayerHistory::default(),
+            }),
+        }
+    }
+
+    pub fn delete_profile(&mut self, name: &str) -> bool {
+        let count = self.profiles.len();
+        self.profiles.retain(|p| !p.name.eq_ignore_ascii_case(name));
+        self.profiles.len() != count
+    }
+
+    pub fn profile_for_terminal(&self, term_id: u8) -> Option<&PlayerProfile> {
+        self.profiles
+            .iter()
+            .find(|p| p.preferred_term_id == Some(term_id))
+    }
+
+    /// Adds the game to the history of the players having a profile
+    pub fn record_game(&mut self, results: &[GameResult]) {
+        for result in results {
+            let Some(profile) = self.profile_mut(&result.name) else {
+                log::debug!("Player '{}' has no profile. Result isn't kept", result.name);
+                continue;
+            };
+
+            let history = &mut profile.history;
+            history.games_played += 1;
+            history.wins += result.is_winner as u32;
+            history.total_score += result.score as i64;
+            history.answered_correctly += result.answered_correctly.max(0) as u32;
+            history.answered_wrong += result.answered_wrong.max(0) as u32;
+        }
+    }
+
+    /// Most wins first. Ties are broken by the total score
+    pub fn leaderboard(&self) -> Vec<&PlayerProfile> {
+        let mut profiles: Vec<&PlayerProfile> = self.profiles.iter().collect();
+        profiles.sort_by(|a, b| {
+            (b.history.wins, b.history.total_score).cmp(&(a.history.wins, a.history.total_score))
+        });
+        profiles
+    }
+
+    fn profile_mut(&mut self, name: &str) -> Option<&mut PlayerProfile> {
+        self.profiles
+            .iter_mut()
+            .find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+}
+
+fn profiles_path() -> PathBuf {
+    let home = home_dir().expect("Expected home directory");
+    home.join("svoyak").join(PROFILES_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::game_result;
+
+    fn result(name: &str, score: i32, is_winner: bool) -> GameResult {
+        GameResult {
+            answered_correctly: 3,
+            answered_wrong: 1,
+            is_winner,
+            ..game_result(name, score)
+        }
+    }
+
+    #[test]
+    fn test_only_players_with_profile_are_recorded() {
+        let mut store = ProfileStore::default();
+        store.save_profile("Alice", "alice.png", Some(1));
+        store.record_game(&[result("alice", 900, true), result("Player 2", 300, false)]);
+
+        let leaderboard = store.leaderboard();
+        assert_eq!(leaderboard.len(), 1);
+        let history = &leaderboard[0].history;
+        assert_eq!(history.games_played, 1);
+        assert_eq!(history.wins, 1);
+        assert_eq!(history.total_score, 900);
+        assert_eq!(history.accuracy(), 0.75);
+    }
+
+    #[test]
+    fn test_terminal_is_taken_from_previous_profile() {
+        let mut store = ProfileStore::default();
+        store.save_profile("Alice", "alice.png", Some(1));
+        store.save_profile("Bob", "bob.png", Some(1));
+
+        assert_eq!(
+            store.profile_for_terminal(1).map(|p| p.name.as_str()),
+            Some("Bob")
+        );
+        store.save_profile("Alice", "new.png", Some(2));
+        assert_eq!(
+            store.profile_for_terminal(2).map(|p| p.icon.as_str()),
+            Some("new.png")
+        );
+    }
+
+    #[test]
+    fn test_leaderboard_is_sorted_by_wins_then_score() {
+        let mut store = ProfileStore::default();
+        ["Alice", "Bob", "Carol"]
+            .iter()
+            .for_each(|name| store.save_profile(name, "", None));
+        store.record_game(&[result("Alice", 100, false), result("Bob", 500, true)]);
+        store.record_game(&[result("Alice", 200, true), result("Carol", 900, false)]);
+
+        let names: Vec<&str> = store
+            .leaderboard()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Bob", "Alice", "Carol"]);
+    }
+}
@@ -0,0 +1,113 @@
+use crate::core::game_entities::{Player, PlayerState};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Who chooses the next question
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum QuestionChooserPolicy {
+    /// Player who answered the last question correctly. The chooser keeps the turn otherwise
+    #[default]
+    LastCorrectAnswerer,
+    /// Next player by terminal id
+    RoundRobin,
+    LowestScore,
+    /// The host appoints the chooser for every question
+    HostPick,
+}
+
+impl QuestionChooserPolicy {
+    /// Returns `None` when the host has to pick the chooser
+    pub fn next_chooser_id(
+        &self,
+        players: &HashMap<u8, Player>,
+        chooser_id: Option<u8>,
+        last_correct_answerer_id: Option<u8>,
+    ) -> Option<u8> {
+        let mut ids = alive_player_ids(players);
+        match self {
+            QuestionChooserPolicy::LastCorrectAnswerer => last_correct_answerer_id
+                .or(chooser_id)
+                .filter(|id| ids.contains(id))
+                .or_else(|| ids.first().copied()),
+            QuestionChooserPolicy::RoundRobin => chooser_id
+                .and_then(|chooser| ids.iter().copied().find(|&id| id > chooser))
+                .or_else(|| ids.first().copied()),
+            QuestionChooserPolicy::LowestScore => {
+                ids.sort_by_key(|id| (players[id].stats.score, *id));
+                ids.first().copied()
+            }
+            QuestionChooserPolicy::HostPick => None,
+        }
+    }
+}
+
+/// Random alive player. Other than the excluded one if possible
+pub fn random_chooser_id(players: &HashMap<u8, Player>, excluded_id: Option<u8>) -> Option<u8> {
+    let ids = alive_player_ids(players);
+    let candidates: Vec<u8> = ids
+        .iter()
+        .copied()
+        .filter(|id| Some(*id) != excluded_id)
+        .collect();
+    let candidates = if candidates.is_empty() {
+        ids
+    } else {
+        candidates
+    };
+    candidates.choose(&mut thread_rng()).copied()
+}
+
+/// Sorted ids of the players still in the game
+fn alive_player_ids(players: &HashMap<u8, Player>) -> Vec<u8> {
+    let mut ids: Vec<u8> = players
+        .values()
+        .filter(|p| p.state != PlayerState::Dead)
+        .map(|p| p.term_id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::scored_players as players;
+
+    #[test]
+    fn test_chooser_keeps_turn_without_correct_answer() {
+        let players = players(&[(1, 0), (2, 0), (3, 0)]);
+        let policy = QuestionChooserPolicy::LastCorrectAnswerer;
+
+        assert_eq!(policy.next_chooser_id(&players, Some(2), None), Some(2));
+        assert_eq!(policy.next_chooser_id(&players, Some(2), Some(3)), Some(3));
+    }
+
+    #[test]
+    fn test_round_robin_skips_dead_players() {
+        let mut players = players(&[(1, 0), (2, 0), (3, 0)]);
+        players.get_mut(&2).expect("Test").state = PlayerState::Dead;
+        let policy = QuestionChooserPolicy::RoundRobin;
+
+        assert_eq!(policy.next_chooser_id(&players, Some(1), None), Some(3));
+        assert_eq!(policy.next_chooser_id(&players, Some(3), None), Some(1));
+    }
+
+    #[test]
+    fn test_lowest_score_chooses() {
+        let players = players(&[(1, 300), (2, -100), (3, 0)]);
+        let policy = QuestionChooserPolicy::LowestScore;
+
+        assert_eq!(policy.next_chooser_id(&players, Some(1), Some(1)), Some(2));
+    }
+
+    #[test]
+    fn test_random_chooser_is_other_player() {
+        let pair = players(&[(1, 0), (2, 0)]);
+        let single = players(&[(1, 0)]);
+
+        assert_eq!(random_chooser_id(&pair, Some(1)), Some(2));
+        assert_eq!(random_chooser_id(&single, Some(1)), Some(1));
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Presses coming this long after the fastest one at most are the lost races
+pub const LOST_RACE_WINDOW_MS: u32 = 1000;
+
+/// Reaction times of valid presses counting from the moment the answer is allowed
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReactionTimes {
+    pub times_ms: Vec<u32>,
+    /// Presses which came after the fastest one
+    pub lost_races: i32,
+}
+
+impl ReactionTimes {
+    fn record(&mut self, reaction_ms: u32, won_race: bool) {
+        self.times_ms.push(reaction_ms);
+        if !won_race {
+            self.lost_races += 1;
+        }
+    }
+
+    pub fn mean_ms(&self) -> Option<u32> {
+        if self.times_ms.is_empty() {
+            return None;
+        }
+        let sum: u64 = self.times_ms.iter().map(|&t| t as u64).sum();
+        Some((sum / self.times_ms.len() as u64) as u32)
+    }
+
+    pub fn median_ms(&self) -> Option<u32> {
+        let mut sorted = self.times_ms.clone();
+        sorted.sort_unstable();
+        let middle = sorted.len() / 2;
+        match sorted.len() {
+            0 => None,
+            len if len % 2 == 1 => Some(sorted[middle]),
+            _ => Some(((sorted[middle - 1] as u64 + sorted[middle] as u64) / 2) as u32),
+        }
+    }
+
+    pub fn best_ms(&self) -> Option<u32> {
+        self.times_ms.iter().copied().min()
+    }
+}
+
+/// Reaction times of the player in the current round and in the whole game
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ReactionStats {
+    pub round: ReactionTimes,
+    pub game: ReactionTimes,
+}
+
+/// Presses after the answer is allowed. The fastest one wins the race, the later ones within
+/// the window lose it, even if they arrive with the next hub polls
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct AnswerRace {
+    allowed_at: u32,
+    winner_pressed_at: Option<u32>,
+    pressed: HashSet<u8>,
+}
+
+impl AnswerRace {
+    pub fn new(allowed_at: u32) -> Self {
+        Self {
+            allowed_at,
+            ..Default::default()
+        }
+    }
+
+    /// Reaction time of the press and whether it won the race. Presses have to come in the order
+    /// of their timestamps. Only the first press of the terminal counts
+    pub fn register(&mut self, term_id: u8, timestamp: u32) -> Option<(u32, bool)> {
+        if timestamp < self.allowed_at {
+            return None;
+        }
+        if let Some(winner_pressed_at) = self.winner_pressed_at {
+            if timestamp.saturating_sub(winner_pressed_at) > LOST_RACE_WINDOW_MS {
+                return None;
+            }
+        }
+        if !self.pressed.insert(term_id) {
+            return None;
+        }
+
+        let won_race = self.winner_pressed_at.is_none();
+        if won_race {
+            self.winner_pressed_at = Some(timestamp);
+        }
+        Some((timestamp - self.allowed_at, won_race))
+    }
+
+    /// Press of the terminal counts for the terminal the player moves to
+    pub fn move_press(&mut self, term_id: u8, new_term_id: u8) {
+        if self.pressed.remove(&term_id) {
+            self.pressed.insert(new_term_id);
+        }
+    }
+}
+
+impl ReactionStats {
+    pub fn record(&mut self, reaction_ms: u32, won_race: bool) {
+        self.round.record(reaction_ms, won_race);
+        self.game.record(reaction_ms, won_race);
+    }
+
+    pub fn start_round(&mut self) {
+        self.round = ReactionTimes::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_of_reaction_times() {
+        let mut stats = ReactionStats::default();
+        assert_eq!(stats.game.mean_ms(), None);
+
+        stats.record(300, true);
+        stats.record(120, false);
+        stats.record(450, false);
+        assert_eq!(stats.game.mean_ms(), Some(290));
+        assert_eq!(stats.game.median_ms(), Some(300));
+        assert_eq!(stats.game.best_ms(), Some(120));
+        assert_eq!(stats.game.lost_races, 2);
+
+        stats.record(200, true);
+        assert_eq!(stats.game.median_ms(), Some(250));
+    }
+
+    #[test]
+    fn test_late_presses_within_window_lose_the_race() {
+        let mut race = AnswerRace::new(10_000);
+
+        assert_eq!(race.register(1, 9_990), None);
+        assert_eq!(race.register(2, 10_200), Some((200, true)));
+        assert_eq!(race.register(2, 10_250), None);
+        // Arrived with the next poll
+        assert_eq!(race.register(3, 10_900), Some((900, false)));
+        assert_eq!(race.register(4, 10_200 + LOST_RACE_WINDOW_MS + 1), None);
+    }
+
+    #[test]
+    fn test_round_is_counted_apart_from_game() {
+        let mut stats = ReactionStats::default();
+        stats.record(300, false);
+        stats.start_round();
+        stats.record(100, true);
+
+        assert_eq!(stats.round.times_ms, vec![100]);
+        assert_eq!(stats.round.lost_races, 0);
+        assert_eq!(stats.game.times_ms, vec![300, 100]);
+        assert_eq!(stats.game.lost_races, 1);
+    }
+}
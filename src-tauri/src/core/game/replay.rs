@@ -0,0 +1,108 @@
+use crate::core::game::journal::{JournalAction, JournalEntry};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Longest pause between replayed entries. Keeps highlights going when the host was away
+pub const MAX_REPLAY_PAUSE: Duration = Duration::from_secs(10);
+
+/// Progress of the game replayed from its journal
+#[derive(Debug, Default, Clone)]
+pub struct Replay {
+    speed: f64,
+    previous_timestamp: Option<u64>,
+    pub entries_replayed: usize,
+    /// Final scores recorded by the journal
+    pub expected_scores: Option<BTreeMap<u8, i32>>,
+    /// Final scores the engine came to while replaying
+    pub replayed_scores: Option<BTreeMap<u8, i32>>,
+}
+
+impl Replay {
+    /// Speed of 2.0 replays the game twice as fast as it was played. Must be positive
+    pub fn new(speed: f64) -> Self {
+        Self {
+            speed,
+            ..Default::default()
+        }
+    }
+
+    /// Transitions follow from the replayed actions, so they aren't replayed themselves
+    pub fn is_replayable(entry: &JournalEntry) -> bool {
+        !matches!(entry.action, JournalAction::Transition)
+    }
+
+    /// Time to wait before the entry is replayed
+    pub fn pause_before(&mut self, entry: &JournalEntry) -> Duration {
+        let previous = self.previous_timestamp.replace(entry.timestamp);
+        let Some(previous) = previous else {
+            return Duration::ZERO;
+        };
+        let gap = Duration::from_millis(entry.timestamp.saturating_sub(previous));
+        // Pauses too long to fit the duration are capped as well
+        Duration::try_from_secs_f64(gap.as_secs_f64() / self.speed)
+            .unwrap_or(MAX_REPLAY_PAUSE)
+            .min(MAX_REPLAY_PAUSE)
+    }
+
+    pub fn is_reproduced(&self) -> bool {
+        self.expected_scores.is_some() && self.expected_scores == self.replayed_scores
+    }
+}
+
+/// Moves the recorded click to the time the answer is allowed in the replayed game
+pub fn rebase_buzz_timestamp(timestamp: u32, recorded_allowed_at: u32, allowed_at: u32) -> u32 {
+    allowed_at.wrapping_add(timestamp.wrapping_sub(recorded_allowed_at))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(timestamp: u64) -> JournalEntry {
+        JournalEntry {
+            timestamp,
+            action: JournalAction::Transition,
+            state_before: String::new(),
+            state_after: String::new(),
+            player_id: None,
+            question: None,
+            score_delta: 0,
+        }
+    }
+
+    #[test]
+    fn test_pause_is_scaled_by_speed() {
+        let mut replay = Replay::new(2.0);
+
+        assert_eq!(replay.pause_before(&entry(1_000)), Duration::ZERO);
+        assert_eq!(replay.pause_before(&entry(3_000)), Duration::from_secs(1));
+        assert_eq!(replay.pause_before(&entry(60_000)), MAX_REPLAY_PAUSE);
+    }
+
+    #[test]
+    fn test_pause_of_slow_replay_is_capped() {
+        let mut replay = Replay::new(1e-300);
+
+        replay.pause_before(&entry(1_000));
+        assert_eq!(replay.pause_before(&entry(2_000)), MAX_REPLAY_PAUSE);
+    }
+
+    #[test]
+    fn test_buzz_keeps_delay_after_answer_allowed() {
+        assert_eq!(rebase_buzz_timestamp(1_250, 1_000, 50_000), 50_250);
+        assert_eq!(rebase_buzz_timestamp(900, 1_000, 50_000), 49_900);
+        assert_eq!(rebase_buzz_timestamp(5, u32::MAX - 4, 100), 110);
+    }
+
+    #[test]
+    fn test_unfinished_game_is_not_reproduced() {
+        let scores: BTreeMap<u8, i32> = [(1, 500), (2, -100)].into_iter().collect();
+        let mut replay = Replay::new(1.0);
+        assert!(!replay.is_reproduced());
+
+        replay.expected_scores = Some(scores.clone());
+        assert!(!replay.is_reproduced());
+        replay.replayed_scores = Some(scores);
+        assert!(replay.is_reproduced());
+    }
+}
@@ -0,0 +1,219 @@
+use crate::core::game::ctx::game_ctx::INVALID_PLAYER_ID;
+use crate::core::game::game_data::GameData;
+use crate::core::game_entities::{GameplayError, Player, PlayerState};
+
+/// Roster changes available in any game state.
+/// Team plays as a whole, so it's retired by any of its terminals
+impl GameData {
+    /// Latecomer joins with zero score
+    pub fn add_player(&mut self, term_id: u8, name: String) -> Result<(), GameplayError> {
+        self.ensure_terminal_is_free(term_id)?;
+
+        let mut player = Player::new(term_id);
+        player.name = name;
+        player.is_used = true;
+        log::info!("Player '{}' joins at terminal {}", player.name, term_id);
+        self.players.insert(term_id, player);
+        Ok(())
+    }
+
+    /// Retired player keeps the score but takes no part in the game anymore.
+    /// The turn to choose the question passes to the next player
+    pub fn retire_player(&mut self, term_id: u8) -> Result<(), GameplayError> {
+        let term_id = self.team_id_of(term_id);
+        let player = self
+            .players
+            .get(&term_id)
+            .ok_or(GameplayError::PlayerNotPresent(term_id.into()))?;
+        if matches!(player.state, PlayerState::Answering | PlayerState::Target) {
+            log::info!("Player {} is answering. Judge the answer first", term_id);
+            return Err(GameplayError::OperationForbidden);
+        }
+
+        if let Some(auction) = &mut self.auction {
+            auction.remove_participant(term_id)?;
+        }
+        if let Some(final_round) = &mut self.final_round {
+            final_round.remove_participant(term_id)?;
+        }
+
+        let player = self.player_by_id_mut(&term_id);
+        log::info!(
+            "Player {} retires with score {}",
+            term_id,
+            player.stats.score
+        );
+        player.state = PlayerState::Dead;
+        if self.question_chooser_id == Some(term_id) {
+            self.pass_turn_of_retired_chooser(term_id);
+        }
+        Ok(())
+    }
+
+    /// Player continues at another terminal keeping the score and the state.
+    /// Team entry is keyed by the terminal of its first member, so it moves along with the member
+    pub fn move_player(&mut self, term_id: u8, new_term_id: u8) -> Result<(), GameplayError> {
+        self.ensure_terminal_is_free(new_term_id)?;
+        if !self.players.contains_key(&self.team_id_of(term_id)) {
+            return Err(GameplayError::PlayerNotPresent(term_id.into()));
+        }
+
+        if let Some(member) = self.teams.values_mut().find_map(|t| t.member_mut(term_id)) {
+            log::info!(
+                "Team member '{}' moves from terminal {} to {}",
+                member.name,
+                term_id,
+                new_term_id
+            );
+            member.term_id = new_term_id;
+        }
+        if self.players.contains_key(&term_id) {
+            self.move_entry(term_id, new_term_id);
+        }
+        self.false_starts.move_lock(term_id, new_term_id);
+        self.answer_race.move_press(term_id, new_term_id);
+        if self.answering_member_id == Some(term_id) {
+            self.answering_member_id = Some(new_term_id);
+        }
+        Ok(())
+    }
+
+    /// Moves the entry of the solo player or the team with everything referring to it
+    fn move_entry(&mut self, term_id: u8, new_term_id: u8) {
+        let Some(mut player) = self.players.remove(&term_id) else {
+            return;
+        };
+        log::info!(
+            "Player '{}' moves from terminal {} to {}",
+            player.name,
+            term_id,
+            new_term_id
+        );
+        player.term_id = new_term_id;
+        self.players.insert(new_term_id, player);
+        if let Some(mut team) = self.teams.remove(&term_id) {
+            team.id = new_term_id;
+            self.teams.insert(new_term_id, team);
+        }
+
+        if self.active_player_id == term_id {
+            self.active_player_id = new_term_id;
+        }
+        if self.question_chooser_id == Some(term_id) {
+            self.question_chooser_id = Some(new_term_id);
+        }
+        if self.last_correct_answerer_id == Some(term_id) {
+            self.last_correct_answerer_id = Some(new_term_id);
+        }
+        if let Some(auction) = &mut self.auction {
+            auction.move_player(term_id, new_term_id);
+        }
+        if let Some(final_round) = &mut self.final_round {
+            final_round.move_player(term_id, new_term_id);
+        }
+    }
+
+    fn pass_turn_of_retired_chooser(&mut self, term_id: u8) {
+        let policy = self.game_mode.question_chooser_policy;
+        match policy.next_chooser_id(&self.players, Some(term_id), None) {
+            Some(id) => self.set_question_chooser(id),
+            None => {
+                log::info!("Waiting for the host to pick the question chooser");
+                self.question_chooser_id = None;
+                if self.active_player_id == term_id {
+                    self.set_active_player_id(INVALID_PLAYER_ID);
+                }
+            }
+        }
+    }
+
+    fn ensure_terminal_is_free(&self, term_id: u8) -> Result<(), GameplayError> {
+        let is_member = self.teams.values().any(|t| t.has_member(term_id));
+        if term_id == INVALID_PLAYER_ID || self.players.contains_key(&term_id) || is_member {
+            log::info!("Terminal {} is taken", term_id);
+            return Err(GameplayError::TerminalTaken(term_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::auction::Auction;
+    use crate::core::game::reaction_stats::AnswerRace;
+    use crate::core::game::team::TeamSetup;
+    use crate::core::game::test_fixtures::game_data as data;
+
+    #[test]
+    fn test_terminal_can_not_be_taken_twice() {
+        let mut data = data(&[1, 2]);
+        assert!(data.add_player(3, "Latecomer".to_string()).is_ok());
+        assert!(data.add_player(2, "Another".to_string()).is_err());
+        assert!(data.move_player(1, 3).is_err());
+        assert_eq!(data.players[&3].name, "Latecomer");
+    }
+
+    #[test]
+    fn test_retired_chooser_passes_the_turn() {
+        let mut data = data(&[1, 2, 3]);
+        data.players.get_mut(&1).expect("Test").stats.score = 700;
+        data.set_question_chooser(1);
+
+        data.retire_player(1).expect("Test");
+        assert_eq!(data.players[&1].state, PlayerState::Dead);
+        assert_eq!(data.players[&1].stats.score, 700);
+        assert_eq!(data.question_chooser_id, Some(2));
+        assert_eq!(data.players[&2].state, PlayerState::QuestionChooser);
+    }
+
+    #[test]
+    fn test_moved_player_keeps_score_and_turn() {
+        let mut data = data(&[1, 2]);
+        data.players.get_mut(&2).expect("Test").stats.score = 300;
+        data.set_question_chooser(2);
+        data.auction = Some(Auction::new(100, 2, &data.players));
+        data.false_starts.lock(2, 1000);
+
+        data.move_player(2, 5).expect("Test");
+        assert!(!data.players.contains_key(&2));
+        assert_eq!(data.players[&5].stats.score, 300);
+        assert_eq!(data.players[&5].term_id, 5);
+        assert_eq!(data.question_chooser_id, Some(5));
+        assert_eq!(data.active_player_id, 5);
+        let auction = data.auction.as_ref().expect("Test");
+        assert_eq!(auction.chooser_id, 5);
+        assert_eq!(auction.participants, vec![1, 5]);
+        assert!(!data.false_starts.is_locked(2, 500));
+        assert!(data.false_starts.is_locked(5, 500));
+    }
+
+    #[test]
+    fn test_moved_team_member_keeps_the_answer() {
+        let mut data = data(&[1, 2, 3]);
+        data.game_mode.teams = vec![TeamSetup {
+            name: "Owls".to_string(),
+            member_ids: vec![2, 3],
+        }];
+        data.form_teams().expect("Test");
+        data.answer_race = AnswerRace::new(1000);
+        data.register_press(3, 1100);
+        data.answering_member_id = Some(3);
+        data.set_active_player_id(2);
+
+        data.move_player(3, 5).expect("Test");
+        assert_eq!(data.answering_member_id, Some(5));
+        assert_eq!(data.team_id_of(5), 2);
+        assert_eq!(data.answer_race.register(5, 1200), None);
+
+        // Team entry follows its first member
+        data.move_player(2, 6).expect("Test");
+        assert!(!data.players.contains_key(&2));
+        assert_eq!(data.players[&6].name, "Owls");
+        assert_eq!(data.active_player_id, 6);
+        assert_eq!(data.team_id_of(5), 6);
+
+        data.retire_player(5).expect("Test");
+        assert_eq!(data.players[&6].state, PlayerState::Dead);
+    }
+}
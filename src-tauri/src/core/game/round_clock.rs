@@ -0,0 +1,98 @@
+use crate::core::game::clock;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Counts the time spent in the round. Paused while the host judges answers.
+/// Zero duration means the round is not limited in time
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(from = "SavedRoundClock", into = "SavedRoundClock")]
+pub struct RoundClock {
+    duration: Duration,
+    elapsed_before_pause: Duration,
+    running_since: Option<Instant>,
+}
+
+impl RoundClock {
+    pub fn start(duration: Duration) -> Self {
+        log::info!("Round clock started for {:?}", duration);
+        Self {
+            duration,
+            elapsed_before_pause: Duration::ZERO,
+            running_since: Some(clock::now()),
+        }
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(since) = self.running_since.take() {
+            self.elapsed_before_pause += clock::now().saturating_duration_since(since);
+            log::debug!("Round clock paused at {:?}", self.elapsed_before_pause);
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.running_since.is_none() {
+            log::debug!("Round clock resumed at {:?}", self.elapsed_before_pause);
+            self.running_since = Some(clock::now());
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        let running = self
+            .running_since
+            .map(|since| clock::now().saturating_duration_since(since))
+            .unwrap_or_default();
+        self.elapsed_before_pause + running
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed())
+    }
+
+    /// Runs the time out right away. Used to end the replayed round where the played one ended
+    pub fn expire(&mut self) {
+        let elapsed = self.elapsed().max(Duration::from_millis(1));
+        self.duration = elapsed;
+        self.elapsed_before_pause = elapsed;
+        self.running_since = self.running_since.map(|_| clock::now());
+    }
+
+    pub fn is_expired(&self) -> bool {
+        !self.duration.is_zero() && self.elapsed() >= self.duration
+    }
+}
+
+/// `Instant` can't be saved, so the clock is saved as the time already spent in the round
+#[derive(Serialize, Deserialize)]
+struct SavedRoundClock {
+    duration: Duration,
+    elapsed: Duration,
+    is_running: bool,
+}
+
+impl From<RoundClock> for SavedRoundClock {
+    fn from(clock: RoundClock) -> Self {
+        Self {
+            duration: clock.duration,
+            elapsed: clock.elapsed(),
+            is_running: clock.is_running(),
+        }
+    }
+}
+
+impl From<SavedRoundClock> for RoundClock {
+    fn from(saved: SavedRoundClock) -> Self {
+        Self {
+            duration: saved.duration,
+            elapsed_before_pause: saved.elapsed,
+            running_since: saved.is_running.then(clock::now),
+        }
+    }
+}
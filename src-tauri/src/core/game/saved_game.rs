@@ -0,0 +1,152 @@
+use crate::core::game::game_data::{GameData, RoundProgress};
+use crate::core::session::current_session;
+use error_stack::{IntoReport, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use tauri::api::path::home_dir;
+
+pub static SAVED_GAME_FILE_NAME: &str = "saved_game";
+
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+pub enum SavedGameError {
+    #[error("No saved game found")]
+    NotFound,
+    #[error("Can't write saved game")]
+    WriteFailed,
+    #[error("Saved game is corrupted")]
+    Corrupted,
+    #[error("Game pack was changed since the game was saved")]
+    PackChanged,
+    #[error("Saved game is already over")]
+    GameIsOver,
+}
+
+/// Everything needed to resume the game after the app restart
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGame<'a> {
+    pub pack_path: String,
+    pub pack_hash: String,
+    pub state_name: String,
+    /// Game without the pack content. It's reloaded from the pack path.
+    /// Borrowed when saving, so the game isn't copied
+    pub game: Cow<'a, GameData>,
+    #[serde(default)]
+    pub pack_progress: Vec<RoundProgress>,
+    /// Journal the resumed game continues. Saves made before the journal existed have none
+    #[serde(default)]
+    pub journal_path: Option<PathBuf>,
+}
+
+impl SavedGame<'_> {
+    /// Results of the finished game are already recorded, so it isn't played again
+    pub fn ensure_resumable(&self) -> Result<(), SavedGameError> {
+        if self.state_name == "EndTheGame" {
+            return Err(SavedGameError::GameIsOver).into_report();
+        }
+        Ok(())
+    }
+
+    pub fn write(&self) -> Result<(), SavedGameError> {
+        let path = saved_game_path();
+        let json = serde_json::to_string(self)
+            .into_report()
+            .change_context(SavedGameError::WriteFailed)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .into_report()
+                .change_context(SavedGameError::WriteFailed)?;
+        }
+        // Written aside and renamed to not leave a broken save if the app crashes mid-write
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, json)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't write {}", tmp_path.display()))
+            .change_context(SavedGameError::WriteFailed)?;
+        fs::rename(&tmp_path, &path)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't replace {}", path.display()))
+            .change_context(SavedGameError::WriteFailed)
+    }
+
+    pub fn read() -> Result<SavedGame<'static>, SavedGameError> {
+        let path = saved_game_path();
+        let json = fs::read_to_string(&path)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't read {}", path.display()))
+            .change_context(SavedGameError::NotFound)?;
+
+        serde_json::from_str(&json)
+            .into_report()
+            .change_context(SavedGameError::Corrupted)
+    }
+
+    /// Nothing to resume anymore. No error if nothing was saved
+    pub fn delete() -> Result<(), SavedGameError> {
+        let path = saved_game_path();
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e)
+                .into_report()
+                .attach_printable_lazy(|| format!("Can't delete {}", path.display()))
+                .change_context(SavedGameError::WriteFailed),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Every session saves its own game
+fn saved_game_path() -> PathBuf {
+    let home = home_dir().expect("Expected home directory");
+    let file_name = current_session().file_name(SAVED_GAME_FILE_NAME, "json");
+    home.join("svoyak").join(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::session::{close_session, enter_session, open_session};
+
+    fn saved_game(state_name: &str) -> SavedGame<'static> {
+        SavedGame {
+            pack_path: "pack.siq".to_string(),
+            pack_hash: "hash".to_string(),
+            state_name: state_name.to_string(),
+            game: Cow::Owned(GameData::default()),
+            pack_progress: vec![],
+            journal_path: None,
+        }
+    }
+
+    #[test]
+    fn test_finished_game_can_not_be_resumed() {
+        assert!(saved_game("ChooseQuestion").ensure_resumable().is_ok());
+        let error = saved_game("EndTheGame").ensure_resumable().unwrap_err();
+        assert!(matches!(
+            error.current_context(),
+            SavedGameError::GameIsOver
+        ));
+    }
+
+    #[test]
+    fn test_deleted_game_is_not_found() {
+        let session_id = format!("test-saved-game-{}", std::process::id());
+        open_session(&session_id).expect("Test");
+        {
+            let _session = enter_session(Some(&session_id)).expect("Test");
+            saved_game("ChooseQuestion").write().expect("Test");
+            assert_eq!(
+                SavedGame::read().expect("Test").state_name,
+                "ChooseQuestion"
+            );
+
+            SavedGame::delete().expect("Test");
+            let error = SavedGame::read().unwrap_err();
+            assert!(matches!(error.current_context(), SavedGameError::NotFound));
+            assert!(SavedGame::delete().is_ok());
+        }
+        close_session(&session_id).expect("Test");
+    }
+}
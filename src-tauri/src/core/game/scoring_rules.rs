@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub enum WrongAnswerPenalty {
+    #[default]
+    FullPrice,
+    HalfPrice,
+    NoPenalty,
+}
+
+/// Scoring of the league the game is played in
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ScoringRules {
+    pub wrong_answer_penalty: WrongAnswerPenalty,
+    /// Wrong answer can't take the score below zero
+    pub score_floor_at_zero: bool,
+    /// Players with negative score are out at the end of the round
+    pub eliminate_negative_scores: bool,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            wrong_answer_penalty: WrongAnswerPenalty::FullPrice,
+            score_floor_at_zero: false,
+            eliminate_negative_scores: true,
+        }
+    }
+}
+
+impl ScoringRules {
+    pub fn penalty(&self, price: i32) -> i32 {
+        match self.wrong_answer_penalty {
+            WrongAnswerPenalty::FullPrice => price,
+            WrongAnswerPenalty::HalfPrice => price / 2,
+            WrongAnswerPenalty::NoPenalty => 0,
+        }
+    }
+
+    pub fn score_after_wrong_answer(&self, score: i32, price: i32) -> i32 {
+        let score = score - self.penalty(price);
+        if self.score_floor_at_zero {
+            score.max(0)
+        } else {
+            score
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_rules_take_full_price() {
+        let rules = ScoringRules::default();
+
+        assert_eq!(rules.score_after_wrong_answer(100, 300), -200);
+        assert!(rules.eliminate_negative_scores);
+    }
+
+    #[test]
+    fn test_half_penalty_with_floor() {
+        let rules = ScoringRules {
+            wrong_answer_penalty: WrongAnswerPenalty::HalfPrice,
+            score_floor_at_zero: true,
+            ..Default::default()
+        };
+
+        assert_eq!(rules.score_after_wrong_answer(500, 300), 350);
+        assert_eq!(rules.score_after_wrong_answer(100, 300), 0);
+    }
+
+    #[test]
+    fn test_no_penalty() {
+        let rules = ScoringRules {
+            wrong_answer_penalty: WrongAnswerPenalty::NoPenalty,
+            ..Default::default()
+        };
+
+        assert_eq!(rules.score_after_wrong_answer(-100, 500), -100);
+    }
+}
@@ -0,0 +1,107 @@
+use crate::core::game_entities::{GameplayError, Player};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Team requested by the host when the game starts
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TeamSetup {
+    pub name: String,
+    pub member_ids: Vec<u8>,
+}
+
+/// Players with own buzzers sharing one score and state.
+/// The team plays as a single entry of `GameData::players` keyed by the id of its first member.
+/// Members keep their own stats as a breakdown of the team result
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Team {
+    pub id: u8,
+    pub name: String,
+    pub members: Vec<Player>,
+}
+
+impl Team {
+    /// Takes team members out of `players` and puts the team in their place
+    pub fn form(
+        setup: &TeamSetup,
+        players: &mut HashMap<u8, Player>,
+    ) -> Result<Self, GameplayError> {
+        let Some(&id) = setup.member_ids.first() else {
+            log::info!("Team '{}' has no members", setup.name);
+            return Err(GameplayError::InvalidTeam);
+        };
+        if let Some(missing_id) = setup.member_ids.iter().find(|id| !players.contains_key(id)) {
+            log::info!(
+                "Player {} can't join team '{}'. Unknown or already in a team",
+                missing_id,
+                setup.name
+            );
+            return Err(GameplayError::InvalidTeam);
+        }
+
+        let members: Vec<Player> = setup
+            .member_ids
+            .iter()
+            .filter_map(|id| players.remove(id))
+            .collect();
+        let team = Self {
+            id,
+            name: setup.name.clone(),
+            members,
+        };
+        players.insert(id, team.to_player());
+        Ok(team)
+    }
+
+    pub fn has_member(&self, term_id: u8) -> bool {
+        self.members.iter().any(|m| m.term_id == term_id)
+    }
+
+    pub fn member_mut(&mut self, term_id: u8) -> Option<&mut Player> {
+        self.members.iter_mut().find(|m| m.term_id == term_id)
+    }
+
+    fn to_player(&self) -> Player {
+        Player {
+            name: self.name.clone(),
+            icon: self.members[0].icon.clone(),
+            term_id: self.id,
+            is_used: true,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::players;
+
+    fn setup(name: &str, member_ids: &[u8]) -> TeamSetup {
+        TeamSetup {
+            name: name.to_string(),
+            member_ids: member_ids.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_team_replaces_its_members() {
+        let mut players = players(&[1, 2, 3, 4]);
+        let team = Team::form(&setup("Owls", &[2, 4]), &mut players).expect("Test");
+
+        assert_eq!(team.id, 2);
+        assert!(team.has_member(4));
+        let mut ids: Vec<u8> = players.keys().copied().collect();
+        ids.sort();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(players[&2].name, "Owls");
+    }
+
+    #[test]
+    fn test_player_can_not_join_two_teams() {
+        let mut players = players(&[1, 2, 3]);
+        Team::form(&setup("Owls", &[1, 2]), &mut players).expect("Test");
+
+        assert!(Team::form(&setup("Foxes", &[3, 2]), &mut players).is_err());
+        assert!(Team::form(&setup("Empty", &[]), &mut players).is_err());
+    }
+}
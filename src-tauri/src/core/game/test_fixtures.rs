@@ -0,0 +1,41 @@
+//! Game entities shared by the unit tests of the game modules
+
+use crate::core::game::game_data::GameData;
+use crate::core::game::player_profiles::GameResult;
+use crate::core::game_entities::Player;
+use std::collections::HashMap;
+
+pub fn player(term_id: u8, score: i32) -> Player {
+    let mut player = Player::new(term_id);
+    player.stats.score = score;
+    player
+}
+
+/// Players without score by their terminal ids
+pub fn players(ids: &[u8]) -> HashMap<u8, Player> {
+    ids.iter().map(|&id| (id, Player::new(id))).collect()
+}
+
+pub fn scored_players(scores: &[(u8, i32)]) -> HashMap<u8, Player> {
+    scores
+        .iter()
+        .map(|&(id, score)| (id, player(id, score)))
+        .collect()
+}
+
+pub fn game_data(ids: &[u8]) -> GameData {
+    let players = ids.iter().map(|&id| Player::new(id)).collect();
+    GameData::new(players, Default::default())
+}
+
+pub fn game_result(name: &str, score: i32) -> GameResult {
+    GameResult {
+        name: name.to_string(),
+        score,
+        team_id: None,
+        entry_score: score,
+        answered_correctly: 0,
+        answered_wrong: 0,
+        is_winner: false,
+    }
+}
@@ -0,0 +1,338 @@
+use crate::core::game::player_profiles::GameResult;
+use error_stack::{IntoReport, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use tauri::api::path::home_dir;
+
+pub static TOURNAMENT_FILE_NAME: &str = "tournament.json";
+
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+pub enum TournamentError {
+    #[error("Can't read tournament")]
+    ReadFailed,
+    #[error("Can't write tournament")]
+    WriteFailed,
+    #[error("Tournament is corrupted")]
+    Corrupted,
+    #[error("Tournament needs at least one game and players for the first one")]
+    BadSetup,
+}
+
+/// One game of the tournament. Players of the later games may be left empty,
+/// then the best ones of the standings take the seats
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TournamentGame {
+    pub pack_path: String,
+    pub seats: usize,
+    pub players: Vec<String>,
+    pub is_finished: bool,
+}
+
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Standing {
+    pub name: String,
+    pub points: u32,
+    pub games_played: u32,
+    /// Breaks the ties in points
+    pub total_score: i64,
+}
+
+/// Series of games with standings carried between them. Kept in the file under the app directory
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Tournament {
+    pub name: String,
+    /// Points for the first place go first. Places beyond the table get nothing
+    pub points_per_place: Vec<u32>,
+    pub games: Vec<TournamentGame>,
+    pub standings: Vec<Standing>,
+    pub current_game_index: usize,
+}
+
+impl Tournament {
+    pub fn new(
+        name: String,
+        points_per_place: Vec<u32>,
+        games: Vec<TournamentGame>,
+    ) -> Result<Self, TournamentError> {
+        let has_first_players = games.first().map_or(false, |g| !g.players.is_empty());
+        if !has_first_players {
+            return Err(TournamentError::BadSetup).into_report();
+        }
+
+        Ok(Self {
+            name,
+            points_per_place,
+            games,
+            standings: vec![],
+            current_game_index: 0,
+        })
+    }
+
+    /// No tournament if nothing was saved yet
+    pub fn read() -> Result<Option<Self>, TournamentError> {
+        let path = tournament_path();
+        let json = match fs::read_to_string(&path) {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e)
+                    .into_report()
+                    .attach_printable_lazy(|| format!("Can't read {}", path.display()))
+                    .change_context(TournamentError::ReadFailed)
+            }
+        };
+
+        serde_json::from_str(&json)
+            .map(Some)
+            .into_report()
+            .change_context(TournamentError::Corrupted)
+    }
+
+    pub fn write(&self) -> Result<(), TournamentError> {
+        let path = tournament_path();
+        let json = serde_json::to_string_pretty(self)
+            .into_report()
+            .change_context(TournamentError::WriteFailed)?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .into_report()
+                .change_context(TournamentError::WriteFailed)?;
+        }
+        // Written aside and renamed to not leave a broken tournament if the app crashes mid-write
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, json)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't write {}", tmp_path.display()))
+            .change_context(TournamentError::WriteFailed)?;
+        fs::rename(&tmp_path, &path)
+            .into_report()
+            .attach_printable_lazy(|| format!("Can't replace {}", path.display()))
+            .change_context(TournamentError::WriteFailed)
+    }
+
+    pub fn delete() -> Result<(), TournamentError> {
+        match fs::remove_file(tournament_path()) {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e)
+                .into_report()
+                .change_context(TournamentError::WriteFailed),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn current_game(&self) -> Option<&TournamentGame> {
+        self.games.get(self.current_game_index)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current_game().is_none()
+    }
+
+    /// Tells if the game of the pack and players is the one the tournament waits for
+    pub fn is_current_game(&self, pack_path: &str, player_names: &[&str]) -> bool {
+        let Some(game) = self.current_game() else {
+            return false;
+        };
+        game.pack_path == pack_path
+            && game.players.len() == player_names.len()
+            && game
+                .players
+                .iter()
+                .all(|p| player_names.iter().any(|n| n.eq_ignore_ascii_case(p)))
+    }
+
+    /// Awards the points for the places of the finished game and seeds the next one.
+    /// Players with the same score share the place, members of a team share the place of the team
+    pub fn record_game(&mut self, results: &[GameResult]) {
+        let Some(game) = self.games.get_mut(self.current_game_index) else {
+            log::warn!("Tournament '{}' is already finished", self.name);
+            return;
+        };
+        game.is_finished = true;
+
+        let mut entries: Vec<(EntryKey, i32)> = results
+            .iter()
+            .map(|r| (entry_key(r), r.entry_score))
+            .collect();
+        entries.sort();
+        entries.dedup();
+
+        for result in results {
+            let place = entries
+                .iter()
+                .filter(|(_, score)| *score > result.entry_score)
+                .count();
+            let points = self
+                .points_per_place
+                .get(place)
+                .copied()
+                .unwrap_or_default();
+
+            let standing = self.standing_mut(&result.name);
+            standing.points += points;
+            standing.games_played += 1;
+            standing.total_score += result.score as i64;
+        }
+        self.standings
+            .sort_by(|a, b| (b.points, b.total_score).cmp(&(a.points, a.total_score)));
+
+        self.current_game_index += 1;
+        self.seed_current_game();
+    }
+
+    fn seed_current_game(&mut self) {
+        let standings = &self.standings;
+        let Some(game) = self.games.get_mut(self.current_game_index) else {
+            return;
+        };
+        if !game.players.is_empty() {
+            return;
+        }
+
+        let seats = if game.seats == 0 {
+            standings.len()
+        } else {
+            game.seats
+        };
+        game.players = standings
+            .iter()
+            .take(seats)
+            .map(|s| s.name.clone())
+            .collect();
+        log::info!("Seeded players of the next game: {:?}", game.players);
+    }
+
+    fn standing_mut(&mut self, name: &str) -> &mut Standing {
+        let index = self
+            .standings
+            .iter()
+            .position(|s| s.name.eq_ignore_ascii_case(name));
+        let index = index.unwrap_or_else(|| {
+            self.standings.push(Standing {
+                name: name.to_string(),
+                ..Default::default()
+            });
+            self.standings.len() - 1
+        });
+        &mut self.standings[index]
+    }
+}
+
+/// Team or the solo player taking one place in the game
+type EntryKey<'a> = (Option<u8>, &'a str);
+
+fn entry_key(result: &GameResult) -> EntryKey<'_> {
+    match result.team_id {
+        Some(team_id) => (Some(team_id), ""),
+        None => (None, result.name.as_str()),
+    }
+}
+
+fn tournament_path() -> PathBuf {
+    let home = home_dir().expect("Expected home directory");
+    home.join("svoyak").join(TOURNAMENT_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::test_fixtures::game_result as result;
+
+    fn game(seats: usize, players: &[&str]) -> TournamentGame {
+        TournamentGame {
+            pack_path: "pack.siq".to_string(),
+            seats,
+            players: players.iter().map(|p| p.to_string()).collect(),
+            is_finished: false,
+        }
+    }
+
+    #[test]
+    fn test_first_game_needs_players() {
+        let games = vec![game(2, &[]), game(2, &[])];
+        assert!(Tournament::new("League".to_string(), vec![3, 1], games).is_err());
+        assert!(Tournament::new("League".to_string(), vec![3, 1], vec![]).is_err());
+    }
+
+    #[test]
+    fn test_standings_are_carried_and_seed_playoff() {
+        let players = ["Alice", "Bob", "Carol"];
+        let games = vec![game(0, &players), game(0, &players), game(2, &[])];
+        let mut tournament = Tournament::new("League".to_string(), vec![3, 2, 1], games).unwrap();
+
+        tournament.record_game(&[
+            result("Alice", 900),
+            result("Bob", 300),
+            result("Carol", 300),
+        ]);
+        tournament.record_game(&[
+            result("Alice", -100),
+            result("bob", 500),
+            result("Carol", 200),
+        ]);
+
+        let standings: Vec<(&str, u32)> = tournament
+            .standings
+            .iter()
+            .map(|s| (s.name.as_str(), s.points))
+            .collect();
+        assert_eq!(standings, vec![("Bob", 5), ("Alice", 4), ("Carol", 4)]);
+        assert_eq!(
+            tournament.current_game().unwrap().players,
+            vec!["Bob", "Alice"]
+        );
+
+        tournament.record_game(&[result("Bob", 100), result("Alice", 400)]);
+        assert!(tournament.is_finished());
+        assert_eq!(tournament.standings[0].name, "Alice");
+    }
+
+    #[test]
+    fn test_team_members_share_the_place_of_the_team() {
+        let players = ["Alice", "Bob", "Carol"];
+        let games = vec![game(0, &players)];
+        let mut tournament = Tournament::new("League".to_string(), vec![3, 1], games).unwrap();
+        let member = |name: &str, score: i32, team_id: u8, team_score: i32| GameResult {
+            team_id: Some(team_id),
+            entry_score: team_score,
+            ..result(name, score)
+        };
+
+        tournament.record_game(&[
+            member("Alice", 100, 1, 600),
+            member("Bob", 500, 1, 600),
+            result("Carol", 400),
+            member("Dave", 200, 4, 300),
+            member("Eve", 100, 4, 300),
+        ]);
+
+        let points: Vec<(&str, u32)> = tournament
+            .standings
+            .iter()
+            .map(|s| (s.name.as_str(), s.points))
+            .collect();
+        assert_eq!(
+            points,
+            vec![
+                ("Bob", 3),
+                ("Alice", 3),
+                ("Carol", 1),
+                ("Dave", 0),
+                ("Eve", 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_only_seated_players_of_the_pack_play_current_game() {
+        let games = vec![game(0, &["Alice", "Bob"])];
+        let tournament = Tournament::new("League".to_string(), vec![3, 1], games).unwrap();
+
+        assert!(tournament.is_current_game("pack.siq", &["bob", "Alice"]));
+        assert!(!tournament.is_current_game("other.siq", &["Alice", "Bob"]));
+        assert!(!tournament.is_current_game("pack.siq", &["Alice", "Carol"]));
+        assert!(!tournament.is_current_game("pack.siq", &["Alice", "Bob", "Carol"]));
+    }
+}
@@ -1,45 +1,96 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
 use std::io;
+use std::path::PathBuf;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::thread::{sleep, JoinHandle};
+use std::time::Duration;
 
+use error_stack::{IntoReport, Report, ResultExt};
 use tempfile::TempDir;
 
+use crate::core::game::clock;
+use crate::core::game::countdown::{Countdown, CountdownKind};
+use crate::core::game::ctx::game_ctx::GameCtx;
 use crate::core::game::ctx::state_processors::answer_attempt_received::AnswerQuestionResult as Aqr;
 use crate::core::game::ctx::state_processors::check_end_of_round::CheckEndOfRoundResult;
 use crate::core::game::ctx::state_processors::choose_question::ChooseQuestionResult;
+use crate::core::game::ctx::state_processors::eliminate_final_themes::EliminateThemeResult;
+use crate::core::game::ctx::state_processors::init_next_round::InitNextRoundResult;
 use crate::core::game::ctx::state_processors::show_round_stats::RoundStatsResult;
-use crate::core::game::game_data::GameMode;
+use crate::core::game::false_start::{FALSE_START_TERM_COLOR, TERM_COLOR_OFF};
+use crate::core::game::game_data::{GameData, GameMode};
 use crate::core::game::game_state::GameState;
-use crate::core::game_entities::GameplayError;
+use crate::core::game::journal::{
+    buzzes_of, GameJournal, HostCommand, JournalAction, JournalEntry, JournalSnapshot,
+};
+use crate::core::game::player_profiles::{GameResult, ProfileStore};
+use crate::core::game::question_chooser::random_chooser_id;
+use crate::core::game::replay::{rebase_buzz_timestamp, Replay};
+use crate::core::game::saved_game::{SavedGame, SavedGameError};
+use crate::core::game::tournament::Tournament;
+use crate::core::game_entities::{GamePackError, GameplayError, Player};
 use crate::core::game_pack::game_pack_entites::GamePack;
+use crate::core::game_pack::game_pack_loader::load_game_pack;
 use crate::core::game_pack::pack_content_entities::Round;
+use crate::core::session::{current_session, spawn_in_session};
+use crate::host_api::dto::FalseStartDto;
+use crate::host_api::event_sink::{subscribe, unsubscribe, SinkId};
 use crate::host_api::events::{
-    emit_error, emit_game_state, emit_players_by_game_data, emit_question, emit_round,
+    emit_countdown, emit_error, emit_false_start, emit_game_state, emit_pack_info,
+    emit_players_by_game_data, emit_question, emit_replay_result, emit_round, emit_round_time,
+    emit_tournament,
 };
-use crate::hub::hub_api::PlayerEvent;
+use crate::hub::hub_api::{PlayerEvent, TermButtonState};
 use crate::player_server::entities::PsPlayer;
+use crate::player_server::player_server::ps;
+use crate::to_factored_ms;
 
-lazy_static::lazy_static! {
-    static ref GAME_CONTROLLER: Arc<RwLock<GameController >> = Arc::new(RwLock::new(GameController::default()));
-}
+const TICK_INTERVAL_MS: u64 = to_factored_ms!(200);
+/// How many host actions can be undone in a row
+const UNDO_HISTORY_LIMIT: usize = 32;
 
+/// Game of the session the calling thread works with
 pub fn game_mut() -> RwLockWriteGuard<'static, GameController> {
-    GAME_CONTROLLER
+    current_session()
+        .game()
         .write()
         .map_err(|e| format!("Mutex is poisoned: {e:#?}"))
         .expect("Mutex is poisoned")
 }
 
 pub fn game() -> RwLockReadGuard<'static, GameController> {
-    GAME_CONTROLLER
+    current_session()
+        .game()
         .read()
         .map_err(|e| format!("Mutex is poisoned: {e:#?}"))
         .expect("Mutex is poisoned")
 }
 
+/// Doesn't wait for the lock. Used by background threads which can skip an iteration
+pub fn try_game_mut() -> Option<RwLockWriteGuard<'static, GameController>> {
+    current_session().game().try_write().ok()
+}
+
 #[derive(Debug, Default)]
 pub struct GameController {
     pub game_pack: GamePack,
     pub game_state: GameState,
+    ticker_thread_handle: Option<JoinHandle<()>>,
+    reported_round_time_sec: Option<u64>,
+    reported_countdown_sec: Option<u64>,
+    /// States preceding the latest host actions. The most recent is at the back
+    undo_history: VecDeque<GameState>,
+    /// Journal of the game in progress
+    journal: Option<GameJournal>,
+    /// Journal also records the emitted events while it's subscribed
+    journal_sink_id: Option<SinkId>,
+    /// Replayed game isn't journaled, saved or driven by the hub and timers
+    is_replaying: bool,
+    /// Pack and state the replay took over. Restored when the replay is over
+    replaced_by_replay: Option<(GamePack, GameState)>,
+    /// Random first chooser recorded by the journal. Taken by the replayed round start
+    recorded_first_chooser: Option<u8>,
 }
 
 macro_rules! get_ctx_ensuring_state {
@@ -59,11 +110,49 @@ impl GameController {
         data.events.clone()
     }
 
-    // pub fn push_events(&self, events: Vec<PlayerEvent>) {
-    //     let data = self.game_state.game_ctx_ref();
-    //     let mut events_guard = data.events.write().expect("Expected to be able acquire write lock on events");
-    //     events_guard.extend(events);
-    // }
+    /// Stores new events from the hub and lets the current state react on them
+    pub fn push_events(&mut self, events: Vec<PlayerEvent>) {
+        if self.is_replaying {
+            log::debug!("Game is replayed. Dropping hub events: {:?}", events);
+            return;
+        }
+        self.store_and_process_events(events);
+    }
+
+    fn store_and_process_events(&mut self, events: Vec<PlayerEvent>) {
+        let before = JournalSnapshot::of(&self.game_state);
+        let answer_allowed_at = self.game_state.game_ctx_ref().allow_answer_timestamp;
+        buzzes_of(&events, answer_allowed_at)
+            .into_iter()
+            .for_each(|buzz| self.record_to_journal(buzz, &before));
+
+        let data = self.game_state.game_ctx_ref();
+        let mut events_guard = data
+            .events
+            .write()
+            .expect("Expected to be able acquire write lock on events");
+        events_guard.extend(events);
+        drop(events_guard);
+
+        if let Err(e) = self.process_player_events() {
+            log::error!("Can't process player events: {:?}", e);
+            emit_error(e.to_string());
+        }
+    }
+
+    fn process_player_events(&mut self) -> error_stack::Result<(), GameplayError> {
+        match self.game_state {
+            GameState::PickFirstQuestionChooser(_) => self.pick_first_question_chooser(),
+            GameState::WaitingForAnswerRequests(_) => self.process_answer_requests(),
+            GameState::DisplayQuestion(_) => Ok(()),
+            _ => {
+                let events = self.game_state.game_ctx_ref().take_events();
+                self.game_state.game_mut().register_late_presses(&events);
+                log::debug!("Nobody waits for clicks. Dropping events: {:?}", events);
+                Ok(())
+            }
+        }
+    }
 
     pub fn push_new_players(
         &mut self,
@@ -71,9 +160,39 @@ impl GameController {
     ) -> error_stack::Result<(), GameplayError> {
         let ctx = get_ctx_ensuring_state!(self, SetupAndLoading);
 
+        // Player ids are terminal ids. None of the players is added if any id is out of range
+        let players = players
+            .into_iter()
+            .map(|player| match u8::try_from(player.id) {
+                Ok(term_id) => Ok((term_id, player)),
+                Err(_) => Err(Report::new(GameplayError::PlayerNotPresent(player.id))
+                    .attach_printable("Player id is not a terminal id")),
+            })
+            .collect::<error_stack::Result<Vec<_>, GameplayError>>()?;
+        let data = ctx.game_mut();
+        for (term_id, player) in players {
+            data.players.insert(term_id, player.into());
+        }
+        self.apply_player_profiles()
+    }
+
+    /// Players on the preferred terminals of the profiles get the profile names and icons
+    pub fn apply_player_profiles(&mut self) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, SetupAndLoading);
+
+        let store = ProfileStore::read().unwrap_or_else(|e| {
+            log::error!(
+                "Can't read player profiles. Players keep their names: {:?}",
+                e
+            );
+            ProfileStore::default()
+        });
         let data = ctx.game_mut();
-        for player in players {
-            data.players.insert(player.id as u8, player.into());
+        for player in data.players.values_mut() {
+            if let Some(profile) = store.profile_for_terminal(player.term_id) {
+                player.name = profile.name.clone();
+                player.icon = profile.icon.clone();
+            }
         }
         emit_players_by_game_data(data);
         Ok(())
@@ -109,19 +228,68 @@ impl GameController {
     ) -> error_stack::Result<(), GameplayError> {
         let ctx = get_ctx_ensuring_state!(self, SetupAndLoading);
 
+        let mut players: Vec<Player> = ctx.game_ref().players.values().cloned().collect();
+        players.sort_by_key(|p| p.term_id);
         let content = self.game_pack.content.clone();
         let ctx = ctx.start(content, game_mode)?;
+        let player_names: Vec<&str> = players.iter().map(|p| p.name.as_str()).collect();
+        self.check_tournament_game(&player_names);
+        self.undo_history.clear();
+        self.start_journal(players);
+        self.ensure_ticker_started();
         self.set_game_state(GameState::StartNextRound(ctx));
         self.init_next_round()?;
-        self.pick_first_question_chooser()?;
+        Ok(())
+    }
+
+    /// Restores the game saved before the app was closed. The pack is reloaded from its path
+    pub fn resume_saved_game(&mut self) -> error_stack::Result<(), GameplayError> {
+        get_ctx_ensuring_state!(self, SetupAndLoading);
+
+        let saved_game = SavedGame::read().change_context(GameplayError::ResumeFailed)?;
+        saved_game
+            .ensure_resumable()
+            .change_context(GameplayError::ResumeFailed)?;
+        let pack =
+            load_game_pack(&saved_game.pack_path).change_context(GameplayError::ResumeFailed)?;
+        if pack.hash != saved_game.pack_hash {
+            return Err(Report::new(SavedGameError::PackChanged)
+                .attach_printable(format!("Pack path: {}", saved_game.pack_path))
+                .change_context(GameplayError::ResumeFailed));
+        }
+        let mut game = saved_game.game.into_owned();
+        game.restore_pack_content(pack.content.clone(), &saved_game.pack_progress);
+        let state = GameState::from_name_and_game(&saved_game.state_name, game)
+            .ok_or(SavedGameError::Corrupted)
+            .into_report()
+            .attach_printable_lazy(|| format!("Unknown state: {}", saved_game.state_name))
+            .change_context(GameplayError::ResumeFailed)?;
+        log::info!(
+            "Resuming the game of pack '{}' from state {}",
+            saved_game.pack_path,
+            saved_game.state_name
+        );
+
+        emit_pack_info((&pack.content).into());
+        self.game_pack = pack;
+        self.undo_history.clear();
+        self.set_journal(saved_game.journal_path.map(GameJournal::open));
+        self.ensure_ticker_started();
+        self.set_game_state(state);
+        self.emit_game_context();
         Ok(())
     }
 
     pub fn pick_first_question_chooser(&mut self) -> error_stack::Result<(), GameplayError> {
+        if self.is_manual_buzzers() {
+            log::debug!("No buzzers. Waiting for the host to pick the first chooser");
+            return Ok(());
+        }
         let ctx = get_ctx_ensuring_state!(self, PickFirstQuestionChooser);
 
-        let ctx = ctx.pick_first_question_chooser()?;
-        self.set_game_state(GameState::ChooseQuestion(ctx));
+        if let Some(ctx) = ctx.pick_first_question_chooser()? {
+            self.set_game_state(GameState::ChooseQuestion(ctx));
+        }
         Ok(())
     }
 
@@ -134,28 +302,156 @@ impl GameController {
             ChooseQuestionResult::AnswerAttemptReceived(ctx) => {
                 GameState::AnswerAttemptReceived(ctx)
             }
+            ChooseQuestionResult::PickPipVictim(ctx) => GameState::PickPipVictim(ctx),
+            ChooseQuestionResult::AuctionBidding(ctx) => GameState::AuctionBidding(ctx),
         };
         self.set_game_state(state);
         Ok(())
     }
 
+    pub fn set_question_chooser(
+        &mut self,
+        player_id: u8,
+    ) -> error_stack::Result<(), GameplayError> {
+        if let GameState::PickFirstQuestionChooser(ctx) = &mut self.game_state {
+            let ctx = ctx.set_first_question_chooser(player_id)?;
+            self.set_game_state(GameState::ChooseQuestion(ctx));
+            return Ok(());
+        }
+        let ctx = get_ctx_ensuring_state!(self, ChooseQuestion);
+
+        let ctx = ctx.set_question_chooser(player_id)?;
+        self.set_game_state(GameState::ChooseQuestion(ctx));
+        Ok(())
+    }
+
+    pub fn pick_random_question_chooser(&mut self) -> error_stack::Result<(), GameplayError> {
+        if let GameState::PickFirstQuestionChooser(ctx) = &mut self.game_state {
+            let ctx = ctx.pick_random_first_question_chooser()?;
+            self.set_game_state(GameState::ChooseQuestion(ctx));
+            return Ok(());
+        }
+        let ctx = get_ctx_ensuring_state!(self, ChooseQuestion);
+
+        let ctx = ctx.pick_random_question_chooser()?;
+        self.set_game_state(GameState::ChooseQuestion(ctx));
+        Ok(())
+    }
+
+    fn pass_turn_on_timeout(&mut self, player_id: u8) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, ChooseQuestion);
+
+        let ctx = ctx.pass_turn_on_timeout(player_id)?;
+        self.set_game_state(GameState::ChooseQuestion(ctx));
+        Ok(())
+    }
+
+    fn select_random_question(&mut self) -> error_stack::Result<(), GameplayError> {
+        let (topic, price) = self.game_state.game_ctx_ref().random_question().ok_or(
+            GameplayError::PackElementNotPresent(GamePackError::QuestionNotPresent),
+        )?;
+        log::info!(
+            "Playing random question. Topic: {}, price: {}",
+            topic,
+            price
+        );
+        Ok(self.select_question(&topic, price)?)
+    }
+
+    pub fn send_pip_victim(&mut self, victim_id: u8) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, PickPipVictim);
+
+        let ctx = ctx.send_pip_victim(victim_id)?;
+        self.set_game_state(GameState::AnswerAttemptReceived(ctx));
+        Ok(())
+    }
+
+    pub fn place_auction_bid(
+        &mut self,
+        player_id: u8,
+        amount: i32,
+    ) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AuctionBidding);
+
+        ctx.place_auction_bid(player_id, amount)?;
+        self.finish_auction_if_decided()
+    }
+
+    pub fn place_auction_all_in(
+        &mut self,
+        player_id: u8,
+    ) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AuctionBidding);
+
+        ctx.place_auction_all_in(player_id)?;
+        self.finish_auction_if_decided()
+    }
+
+    pub fn pass_auction(&mut self, player_id: u8) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AuctionBidding);
+
+        ctx.pass_auction(player_id)?;
+        self.finish_auction_if_decided()
+    }
+
+    pub fn finish_auction(&mut self) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AuctionBidding);
+
+        let ctx = ctx.finish_auction()?;
+        self.set_game_state(GameState::AnswerAttemptReceived(ctx));
+        Ok(())
+    }
+
     pub fn allow_answer(&mut self) -> error_stack::Result<(), GameplayError> {
         let ctx = get_ctx_ensuring_state!(self, DisplayQuestion);
 
         let ctx = ctx.allow_answer()?;
         self.set_game_state(GameState::WaitingForAnswerRequests(ctx));
-        Ok(())
+        self.process_answer_requests()
     }
 
-    pub fn wait_for_quickest_player_to_click(&mut self) -> error_stack::Result<(), GameplayError> {
+    /// Gives the answer to the quickest player if somebody has already clicked
+    pub fn process_answer_requests(&mut self) -> error_stack::Result<(), GameplayError> {
+        if self.is_manual_buzzers() {
+            log::debug!("No buzzers. Waiting for the host to give the answer");
+            return Ok(());
+        }
         let ctx = get_ctx_ensuring_state!(self, WaitingForAnswerRequests);
 
-        let id = ctx.get_fastest_click_player_id()?;
+        ctx.register_false_starts()
+            .into_iter()
+            .for_each(|id| ps().set_term_light_color(id, FALSE_START_TERM_COLOR));
+        let Some(id) = ctx.get_fastest_click_player_id()? else {
+            return Ok(());
+        };
         let ctx = ctx.request_answer_by_player_id(id)?;
         self.set_game_state(GameState::AnswerAttemptReceived(ctx));
         Ok(())
     }
 
+    fn is_manual_buzzers(&self) -> bool {
+        self.game_state.game_ctx_ref().game_mode.manual_buzzers
+    }
+
+    pub fn request_answer_by_player_id(
+        &mut self,
+        player_id: u8,
+    ) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, WaitingForAnswerRequests);
+
+        let ctx = ctx.override_answer_request(player_id)?;
+        self.set_game_state(GameState::AnswerAttemptReceived(ctx));
+        Ok(())
+    }
+
+    pub fn cancel_answer_requests(&mut self) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, WaitingForAnswerRequests);
+
+        let ctx = ctx.cancel_answer_requests()?;
+        self.set_game_state(GameState::DisplayQuestion(ctx));
+        Ok(())
+    }
+
     pub fn answer_question(
         &mut self,
         answered_correctly: bool,
@@ -171,6 +467,11 @@ impl GameController {
     }
 
     pub fn stop_asking_and_show_answer(&mut self) -> error_stack::Result<(), GameplayError> {
+        if let GameState::WaitingForAnswerRequests(ctx) = &mut self.game_state {
+            let ctx = ctx.finish_question_preemptively()?;
+            self.set_game_state(GameState::EndQuestion(ctx));
+            return Ok(());
+        }
         let ctx = get_ctx_ensuring_state!(self, DisplayQuestion);
 
         let ctx = ctx.finish_question_preemptively()?;
@@ -206,11 +507,13 @@ impl GameController {
             RoundStatsResult::StartNextRound(ctx) => {
                 self.set_game_state(GameState::StartNextRound(ctx));
                 self.init_next_round()?;
-                self.pick_first_question_chooser()?;
             }
             RoundStatsResult::EndTheGame(ctx, reason) => {
                 ctx.calculate_final_results(reason);
-                self.set_game_state(GameState::EndTheGame(ctx))
+                self.set_game_state(GameState::EndTheGame(ctx));
+                let before = JournalSnapshot::of(&self.game_state);
+                let scores = JournalSnapshot::scores(self.game_state.game_ctx_ref());
+                self.record_to_journal(JournalAction::GameEnded { scores }, &before);
             }
         }
         Ok(())
@@ -219,48 +522,714 @@ impl GameController {
     fn init_next_round(&mut self) -> error_stack::Result<(), GameplayError> {
         let ctx = get_ctx_ensuring_state!(self, StartNextRound);
 
-        let ctx = ctx.init_next_round()?;
-        self.set_game_state(GameState::PickFirstQuestionChooser(ctx));
+        match ctx.init_next_round()? {
+            InitNextRoundResult::PickFirstQuestionChooser(ctx) => {
+                let random_first_chooser = ctx.game_ref().game_mode.random_first_chooser;
+                self.set_game_state(GameState::PickFirstQuestionChooser(ctx));
+                if random_first_chooser {
+                    self.pick_random_first_chooser()?;
+                } else {
+                    self.pick_first_question_chooser()?;
+                }
+            }
+            InitNextRoundResult::EliminateFinalThemes(ctx) => {
+                self.set_game_state(GameState::EliminateFinalThemes(ctx))
+            }
+            InitNextRoundResult::PlaceFinalWagers(ctx) => {
+                self.set_game_state(GameState::PlaceFinalWagers(ctx))
+            }
+            InitNextRoundResult::ShowRoundStats(ctx) => {
+                self.set_game_state(GameState::ShowRoundStats(ctx))
+            }
+        }
+        Ok(())
+    }
+
+    /// The pick is journaled, so the replay takes the same chooser instead of rolling again
+    fn pick_random_first_chooser(&mut self) -> error_stack::Result<(), GameplayError> {
+        if self.is_replaying {
+            match self.recorded_first_chooser.take() {
+                Some(player_id) => return self.set_question_chooser(player_id),
+                None => log::warn!("Journal has no first chooser pick. Picking it randomly"),
+            }
+        }
+
+        let before = JournalSnapshot::of(&self.game_state);
+        self.pick_random_question_chooser()?;
+        if let Some(player_id) = self.game_state.game_ctx_ref().question_chooser_id {
+            let action = JournalAction::RandomChooserPicked { player_id };
+            self.record_to_journal(action, &before);
+        }
+        Ok(())
+    }
+
+    // Final round API
+    pub fn eliminate_final_theme(&mut self, theme: &str) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, EliminateFinalThemes);
+
+        let path = ctx.eliminate_final_theme(theme)?;
+        self.set_game_state(match path {
+            EliminateThemeResult::EliminateFinalThemes(ctx) => GameState::EliminateFinalThemes(ctx),
+            EliminateThemeResult::PlaceFinalWagers(ctx) => GameState::PlaceFinalWagers(ctx),
+        });
+        Ok(())
+    }
+
+    pub fn place_final_wager(
+        &mut self,
+        player_id: u8,
+        amount: i32,
+    ) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, PlaceFinalWagers);
+
+        ctx.place_final_wager(player_id, amount)?;
+        if ctx.are_final_wagers_placed() {
+            let ctx = ctx.show_final_question()?;
+            self.set_game_state(GameState::AnswerFinalQuestion(ctx));
+        }
+        Ok(())
+    }
+
+    pub fn judge_final_answer(
+        &mut self,
+        player_id: u8,
+        answered_correctly: bool,
+    ) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AnswerFinalQuestion);
+
+        ctx.judge_final_answer(player_id, answered_correctly)?;
+        if ctx.are_final_answers_judged() {
+            let ctx = ctx.settle_final_round()?;
+            self.set_game_state(GameState::ShowRoundStats(ctx));
+        }
         Ok(())
     }
 
     pub fn finish_game(&mut self) -> error_stack::Result<(), GameplayError> {
         let ctx = get_ctx_ensuring_state!(self, EndTheGame);
 
+        let results = ctx.game_ref().game_results();
         let ctx = ctx.finish_game()?;
+        self.undo_history.clear();
         self.set_game_state(GameState::SetupAndLoading(ctx));
+        self.delete_saved_game();
+        self.record_game_results(&results);
+        self.record_tournament_results(&results);
         Ok(())
     }
 
-    pub fn edit_player_score(&mut self, player_id: i32, score: i32) -> error_stack::Result<(), GameplayError> {
+    /// Finished or dropped game isn't resumed anymore. Errors are only logged
+    fn delete_saved_game(&self) {
+        if !self.is_persisted() {
+            return;
+        }
+        if let Err(e) = SavedGame::delete() {
+            log::error!("Can't delete the saved game: {:?}", e);
+        }
+    }
+
+    /// Errors are only logged as the history of the players doesn't affect the game
+    fn record_game_results(&self, results: &[GameResult]) {
+        if !self.is_persisted() {
+            return;
+        }
+
+        let recorded = ProfileStore::read().and_then(|mut store| {
+            store.record_game(results);
+            store.write()
+        });
+        if let Err(e) = recorded {
+            log::error!("Can't record the game to player profiles: {:?}", e);
+            emit_error(e.to_string());
+        }
+    }
+
+    /// Warns the host when the game is started aside of the running tournament.
+    /// Such a game doesn't count to it
+    fn check_tournament_game(&self, player_names: &[&str]) {
+        if !self.is_persisted() {
+            return;
+        }
+
+        match Tournament::read() {
+            Ok(Some(tournament)) => {
+                let Some(game) = tournament.current_game() else {
+                    return;
+                };
+                if !tournament.is_current_game(&self.game_pack.path, player_names) {
+                    emit_error(format!(
+                        "Game doesn't count to tournament '{}'. It expects pack '{}' with players {:?}",
+                        tournament.name, game.pack_path, game.players
+                    ));
+                }
+            }
+            Ok(None) => {}
+            Err(e) => log::error!("Can't read the tournament: {:?}", e),
+        }
+    }
+
+    /// Finished game counts to the tournament if it's the game the tournament waits for
+    fn record_tournament_results(&self, results: &[GameResult]) {
+        if !self.is_persisted() {
+            return;
+        }
+
+        let player_names: Vec<&str> = results.iter().map(|r| r.name.as_str()).collect();
+        let recorded = Tournament::read().and_then(|tournament| {
+            let Some(mut tournament) = tournament else {
+                return Ok(None);
+            };
+            if !tournament.is_current_game(&self.game_pack.path, &player_names) {
+                log::info!(
+                    "Game of pack '{}' with {:?} isn't the current game of tournament '{}'",
+                    self.game_pack.path,
+                    player_names,
+                    tournament.name
+                );
+                return Ok(None);
+            }
+            tournament.record_game(results);
+            tournament.write().map(|_| Some(tournament))
+        });
+        match recorded {
+            Ok(Some(tournament)) => emit_tournament((&tournament).into()),
+            Ok(None) => {}
+            Err(e) => {
+                log::error!("Can't record the game to the tournament: {:?}", e);
+                emit_error(e.to_string());
+            }
+        }
+    }
+
+    pub fn edit_player_score(
+        &mut self,
+        player_id: u8,
+        score: i32,
+    ) -> error_stack::Result<(), GameplayError> {
         log::info!("received: player: {player_id} score {score}");
 
         let data = self.game_state.game_mut();
-        let player = data.players.get_mut(&(player_id as u8)).ok_or(GameplayError::PlayerNotFound)?;
+        let player = data
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameplayError::PlayerNotPresent(player_id.into()))?;
         player.stats.score = score;
         emit_players_by_game_data(data);
         Ok(())
     }
 
+    /// Roster changes are allowed in any state, so terminals can be replaced during the game
+    pub fn add_player(
+        &mut self,
+        player_id: u8,
+        name: String,
+    ) -> error_stack::Result<(), GameplayError> {
+        self.game_state.game_mut().add_player(player_id, name)?;
+        self.emit_game_context();
+        self.autosave();
+        Ok(())
+    }
+
+    pub fn retire_player(&mut self, player_id: u8) -> error_stack::Result<(), GameplayError> {
+        self.game_state.game_mut().retire_player(player_id)?;
+        self.emit_game_context();
+        self.autosave();
+        Ok(())
+    }
+
+    pub fn move_player(
+        &mut self,
+        player_id: u8,
+        new_player_id: u8,
+    ) -> error_stack::Result<(), GameplayError> {
+        self.game_state
+            .game_mut()
+            .move_player(player_id, new_player_id)?;
+        self.emit_game_context();
+        self.autosave();
+        Ok(())
+    }
+
     /// Used to reset everything
     pub fn reset_the_game(&mut self) {
         self.game_pack = GamePack::default();
         self.game_state = GameState::default();
+        self.undo_history.clear();
+        self.set_journal(None);
+        self.delete_saved_game();
+        self.emit_game_context();
+    }
+}
+
+/// Undo API
+impl GameController {
+    /// Runs the host action. The preceding state is remembered only if the action succeeds
+    pub fn run_undoable<T, E, F>(&mut self, action: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let mut snapshot = self.game_state.clone();
+        // Cloned state shares the buffer of the presses with the live one.
+        // Presses stored after the snapshot belong to the live state, so the snapshot gets its own
+        snapshot.game_mut().events = Default::default();
+        let result = action(self);
+        if result.is_ok() {
+            self.undo_history.push_back(snapshot);
+            if self.undo_history.len() > UNDO_HISTORY_LIMIT {
+                self.undo_history.pop_front();
+            }
+        }
+        result
+    }
+
+    /// Restores the state preceding the last host action: scores, stats and used questions.
+    /// The round clock keeps running unless the round itself is undone
+    pub fn undo_last_action(&mut self) -> error_stack::Result<(), GameplayError> {
+        let Some(mut state) = self.undo_history.pop_back() else {
+            log::info!("Undo history is empty");
+            return Err(GameplayError::NothingToUndo.into());
+        };
+        log::info!("Undoing the last action. Restoring state: {}", state.name());
+
+        let current = self.game_state.game_ctx_ref();
+        let restored = state.game_mut();
+        if restored.current_round_index() == current.current_round_index() {
+            restored.round_clock = current.round_clock.clone();
+        }
+
+        self.set_game_state(state);
         self.emit_game_context();
+        Ok(())
     }
 }
 
+/// Journal API
+impl GameController {
+    /// Runs the host command and records it to the game journal if it succeeds
+    pub fn run_host_command(
+        &mut self,
+        command: HostCommand,
+    ) -> error_stack::Result<(), GameplayError> {
+        let before = JournalSnapshot::of(&self.game_state);
+        if command.is_undoable() {
+            self.run_undoable(|game| game.execute_host_command(&command))?;
+        } else {
+            self.execute_host_command(&command)?;
+        }
+        self.record_to_journal(JournalAction::HostCommand(command), &before);
+        Ok(())
+    }
+
+    fn execute_host_command(
+        &mut self,
+        command: &HostCommand,
+    ) -> error_stack::Result<(), GameplayError> {
+        match command {
+            HostCommand::StartNewGame { game_mode } => self.start_new_game(game_mode.clone()),
+            HostCommand::SelectQuestion { topic, price } => {
+                Ok(self.select_question(topic, *price)?)
+            }
+            HostCommand::AllowAnswer => self.allow_answer(),
+            HostCommand::RequestAnswer { player_id } => {
+                self.request_answer_by_player_id(*player_id)
+            }
+            HostCommand::CancelAnswerRequests => self.cancel_answer_requests(),
+            HostCommand::AnswerQuestion { answered_correctly } => {
+                self.answer_question(*answered_correctly)
+            }
+            HostCommand::StopAskingAndShowAnswer => self.stop_asking_and_show_answer(),
+            HostCommand::FinishQuestion => self.finish_question(),
+            HostCommand::InitNextRound => self.process_end_of_round(),
+            HostCommand::SetQuestionChooser { player_id } => self.set_question_chooser(*player_id),
+            HostCommand::PickRandomQuestionChooser => self.pick_random_question_chooser(),
+            HostCommand::SendPipVictim { victim_id } => self.send_pip_victim(*victim_id),
+            HostCommand::PlaceAuctionBid { player_id, amount } => {
+                self.place_auction_bid(*player_id, *amount)
+            }
+            HostCommand::PlaceAuctionAllIn { player_id } => self.place_auction_all_in(*player_id),
+            HostCommand::PassAuction { player_id } => self.pass_auction(*player_id),
+            HostCommand::FinishAuction => self.finish_auction(),
+            HostCommand::EliminateFinalTheme { theme } => self.eliminate_final_theme(theme),
+            HostCommand::PlaceFinalWager { player_id, amount } => {
+                self.place_final_wager(*player_id, *amount)
+            }
+            HostCommand::JudgeFinalAnswer {
+                player_id,
+                answered_correctly,
+            } => self.judge_final_answer(*player_id, *answered_correctly),
+            HostCommand::EditPlayerScore { player_id, score } => {
+                self.edit_player_score(*player_id, *score)
+            }
+            HostCommand::AddPlayer { player_id, name } => self.add_player(*player_id, name.clone()),
+            HostCommand::RetirePlayer { player_id } => self.retire_player(*player_id),
+            HostCommand::MovePlayer {
+                player_id,
+                new_player_id,
+            } => self.move_player(*player_id, *new_player_id),
+            HostCommand::UndoLastAction => self.undo_last_action(),
+            HostCommand::FinishGame => self.finish_game(),
+        }
+    }
+
+    /// Every game gets its own journal. It starts with the pack and players of the game
+    fn start_journal(&mut self, players: Vec<Player>) {
+        if !self.is_persisted() {
+            return;
+        }
+        let journal = GameJournal::create();
+        let snapshot = JournalSnapshot::of(&self.game_state);
+        let action = JournalAction::GameStarted {
+            pack_path: self.game_pack.path.clone(),
+            pack_hash: self.game_pack.hash.clone(),
+            players,
+        };
+        let entry = JournalEntry::new(action, &snapshot, &snapshot);
+        if let Err(e) = journal.append(&entry) {
+            log::error!("Can't start the game journal: {:?}", e);
+        }
+        self.set_journal(Some(journal));
+    }
+
+    /// New journal replaces the previous one as the event sink
+    fn set_journal(&mut self, journal: Option<GameJournal>) {
+        if let Some(id) = self.journal_sink_id.take() {
+            unsubscribe(id);
+        }
+        self.journal_sink_id = journal.clone().map(|j| subscribe(Box::new(j)));
+        self.journal = journal;
+    }
+
+    /// Appends the entry describing the change since `before`. Errors are only logged
+    fn record_to_journal(&self, action: JournalAction, before: &JournalSnapshot) {
+        let Some(journal) = &self.journal else {
+            return;
+        };
+
+        let entry = JournalEntry::new(action, before, &JournalSnapshot::of(&self.game_state));
+        if let Err(e) = journal.append(&entry) {
+            log::error!("Can't write the game journal: {:?}", e);
+        }
+    }
+}
+
+/// Replay API
+impl GameController {
+    /// Replays the journal of the game in the background. Only possible between the games
+    pub fn start_replay(
+        &mut self,
+        journal_path: PathBuf,
+        speed: f64,
+    ) -> error_stack::Result<(), GameplayError> {
+        get_ctx_ensuring_state!(self, SetupAndLoading);
+        if self.is_replaying {
+            log::info!("Another game is being replayed");
+            return Err(Report::new(GameplayError::OperationForbidden));
+        }
+        if !speed.is_finite() || speed <= 0.0 {
+            return Err(Report::new(GameplayError::InvalidReplaySpeed)
+                .attach_printable(format!("Speed: {}", speed)));
+        }
+
+        let entries = GameJournal::open(journal_path)
+            .read_entries()
+            .change_context(GameplayError::ReplayFailed)?;
+        log::info!(
+            "Replaying {} journal entries at speed {}",
+            entries.len(),
+            speed
+        );
+
+        self.is_replaying = true;
+        self.replaced_by_replay = Some((self.game_pack.clone(), self.game_state.clone()));
+        self.set_journal(None);
+        self.ensure_ticker_started();
+        spawn_in_session(move || run_replay(entries, Replay::new(speed)));
+        Ok(())
+    }
+
+    /// Runs the recorded action through the same transitions as the played game.
+    /// Random picks and the round time are taken from the journal to reproduce the game
+    fn replay_entry(
+        &mut self,
+        entry: &JournalEntry,
+        replay: &mut Replay,
+    ) -> error_stack::Result<(), GameplayError> {
+        match &entry.action {
+            JournalAction::GameStarted {
+                pack_path,
+                pack_hash,
+                players,
+            } => self.prepare_replay(pack_path, pack_hash, players),
+            JournalAction::HostCommand(HostCommand::PickRandomQuestionChooser) => {
+                let player_id = entry.player_id.ok_or(GameplayError::ReplayFailed)?;
+                self.run_host_command(HostCommand::SetQuestionChooser { player_id })
+            }
+            JournalAction::HostCommand(HostCommand::FinishQuestion)
+                if entry.state_after == "ShowRoundStats" =>
+            {
+                self.game_state.game_mut().round_clock.expire();
+                self.run_host_command(HostCommand::FinishQuestion)
+            }
+            JournalAction::HostCommand(command) => self.run_host_command(command.clone()),
+            JournalAction::Buzz {
+                term_id,
+                timestamp,
+                answer_allowed_at,
+            } => {
+                let allowed_at = self.game_state.game_ctx_ref().allow_answer_timestamp;
+                self.store_and_process_events(vec![PlayerEvent {
+                    term_id: *term_id,
+                    timestamp: rebase_buzz_timestamp(*timestamp, *answer_allowed_at, allowed_at),
+                    state: TermButtonState::Pressed,
+                }]);
+                Ok(())
+            }
+            JournalAction::Timeout(CountdownKind::QuestionChoice)
+                if entry.state_after != "ChooseQuestion" =>
+            {
+                let question = entry.question.as_ref().ok_or(GameplayError::ReplayFailed)?;
+                self.run_undoable(|game| Ok(game.select_question(&question.topic, question.price)?))
+            }
+            JournalAction::Timeout(CountdownKind::QuestionChoice) => {
+                let player_id = entry.player_id.ok_or(GameplayError::ReplayFailed)?;
+                self.run_undoable(|game| game.pass_turn_on_timeout(player_id))
+            }
+            JournalAction::Timeout(CountdownKind::FirstChooserPick) => {
+                let player_id = entry.player_id.ok_or(GameplayError::ReplayFailed)?;
+                self.run_undoable(|game| game.set_question_chooser(player_id))
+            }
+            JournalAction::Timeout(kind) => self.on_countdown_expired(*kind),
+            JournalAction::RandomChooserPicked { player_id } => {
+                self.recorded_first_chooser = Some(*player_id);
+                Ok(())
+            }
+            JournalAction::GameEnded { scores } => {
+                let data = self.game_state.game_ctx_ref();
+                replay.expected_scores = Some(scores.clone());
+                replay.replayed_scores = Some(JournalSnapshot::scores(data));
+                Ok(())
+            }
+            JournalAction::Transition => Ok(()),
+        }
+    }
+
+    /// Loads the pack and players the recorded game was started with
+    fn prepare_replay(
+        &mut self,
+        pack_path: &str,
+        pack_hash: &str,
+        players: &[Player],
+    ) -> error_stack::Result<(), GameplayError> {
+        let pack = load_game_pack(pack_path).change_context(GameplayError::ReplayFailed)?;
+        if pack.hash != pack_hash {
+            return Err(Report::new(SavedGameError::PackChanged)
+                .attach_printable(format!("Pack path: {}", pack_path))
+                .change_context(GameplayError::ReplayFailed));
+        }
+
+        emit_pack_info((&pack.content).into());
+        self.game_pack = pack;
+        self.undo_history.clear();
+        let data = GameData::new(players.to_vec(), self.get_events_handle());
+        self.set_game_state(GameState::SetupAndLoading(GameCtx::new_with_game(data)));
+        self.emit_game_context();
+        Ok(())
+    }
+
+    /// Brings back the game the replay took over, so the replayed one is never saved or resumed.
+    /// Called whether the replay succeeded or not
+    fn finish_replay(&mut self, replay: &Replay) {
+        log::info!(
+            "Replay is over after {} entries. Final scores reproduced: {}",
+            replay.entries_replayed,
+            replay.is_reproduced()
+        );
+        let (pack, state) = self.replaced_by_replay.take().unwrap_or_default();
+        self.recorded_first_chooser = None;
+        emit_pack_info((&pack.content).into());
+        self.game_pack = pack;
+        self.undo_history.clear();
+        self.set_game_state(state);
+        self.emit_game_context();
+        self.is_replaying = false;
+        emit_replay_result(replay.into());
+    }
+}
+
+fn run_replay(entries: Vec<JournalEntry>, mut replay: Replay) {
+    for entry in entries.iter().filter(|e| Replay::is_replayable(e)) {
+        sleep(replay.pause_before(entry));
+        if current_session().is_closed() {
+            log::info!("Session is closed. Replay is dropped");
+            return;
+        }
+        if let Err(e) = game_mut().replay_entry(entry, &mut replay) {
+            log::error!("Can't replay journal entry {:?}: {:?}", entry, e);
+            emit_error(e.to_string());
+            break;
+        }
+        replay.entries_replayed += 1;
+    }
+    game_mut().finish_replay(&replay);
+}
+
 /// Debug API
 impl GameController {
     pub fn _dbg_set_game_state(&mut self, name: String) {
-        self.set_game_state(GameState::from_name_and_game(
-            &name,
-            self.game_state.game_ctx_ref().clone(),
-        ));
+        let game = self.game_state.game_ctx_ref().clone();
+        let Some(state) = GameState::from_name_and_game(&name, game) else {
+            emit_error(format!("Invalid state name {}", name));
+            return;
+        };
+        self.set_game_state(state);
         self.emit_game_context();
     }
 }
 
+/// Timers API
+impl GameController {
+    /// Called by the ticker thread. Drives everything that depends on time
+    pub fn on_tick(&mut self) {
+        self.release_false_starts();
+        self.report_round_time();
+        self.report_countdown();
+        if let Err(e) = self.handle_expired_countdown() {
+            log::error!("Can't handle expired countdown: {:?}", e);
+            emit_error(e.to_string());
+        }
+    }
+
+    fn release_false_starts(&mut self) {
+        let Ok(now) = clock::epoch_ms() else {
+            return;
+        };
+
+        let data = self.game_state.game_mut();
+        for id in data.false_starts.release_expired(now) {
+            log::info!("False start lockout of player {} is over", id);
+            ps().set_term_light_color(id, TERM_COLOR_OFF);
+            let player = data.players.get(&data.team_id_of(id));
+            emit_false_start(FalseStartDto {
+                playerId: id as i32,
+                playerName: player.map(|p| p.name.clone()).unwrap_or_default(),
+                isLockedOut: false,
+                lockoutMs: 0,
+            });
+        }
+    }
+
+    fn report_countdown(&mut self) {
+        let Some(countdown) = &self.game_state.game_ctx_ref().countdown else {
+            return;
+        };
+
+        let remaining_sec = countdown.remaining().as_secs();
+        if self.reported_countdown_sec == Some(remaining_sec) {
+            return;
+        }
+        self.reported_countdown_sec = Some(remaining_sec);
+        emit_countdown(Some(countdown).into());
+    }
+
+    fn handle_expired_countdown(&mut self) -> error_stack::Result<(), GameplayError> {
+        let data = self.game_state.game_ctx_ref();
+        let Some(countdown) = &data.countdown else {
+            return Ok(());
+        };
+        if !countdown.is_expired() || self.is_replaying {
+            return Ok(());
+        }
+
+        let kind = countdown.kind;
+        let before = JournalSnapshot::of(&self.game_state);
+        let result = self.on_countdown_expired(kind);
+        if result.is_ok() {
+            self.record_to_journal(JournalAction::Timeout(kind), &before);
+        }
+        result
+    }
+
+    fn on_countdown_expired(
+        &mut self,
+        kind: CountdownKind,
+    ) -> error_stack::Result<(), GameplayError> {
+        let game_mode = &self.game_state.game_ctx_ref().game_mode;
+        let wrong_answer_on_timeout = game_mode.wrong_answer_on_timeout;
+        let chooser_picked_on_timeout = self.game_state.game_ctx_ref().chooser_picked_on_timeout;
+        match kind {
+            CountdownKind::BuzzWindow => {
+                log::info!("Buzz window is over. Nobody requested the answer");
+                self.stop_asking_and_show_answer()
+            }
+            CountdownKind::Answer if wrong_answer_on_timeout => {
+                log::info!("Answer time is over. Judging the answer as wrong");
+                self.run_undoable(|game| game.answer_question(false))
+            }
+            CountdownKind::Answer => {
+                log::info!("Answer time is over. Waiting for the host to judge the answer");
+                self.game_state.game_mut().countdown = None;
+                emit_countdown(None::<&Countdown>.into());
+                Ok(())
+            }
+            CountdownKind::QuestionChoice if chooser_picked_on_timeout => {
+                log::info!("Question isn't chosen in time again. Picking a random question");
+                self.run_undoable(|game| game.select_random_question())
+            }
+            CountdownKind::QuestionChoice => {
+                log::info!("Question isn't chosen in time. Picking a random chooser");
+                let data = self.game_state.game_ctx_ref();
+                let player_id = random_chooser_id(&data.players, data.question_chooser_id)
+                    .ok_or(GameplayError::NoActivePlayersLeft)?;
+                self.run_undoable(|game| game.pass_turn_on_timeout(player_id))
+            }
+            CountdownKind::FirstChooserPick => {
+                log::info!("Nobody pressed for the first turn. Picking a random chooser");
+                self.run_undoable(|game| game.pick_random_question_chooser())
+            }
+        }
+    }
+
+    fn report_round_time(&mut self) {
+        let clock = &self.game_state.game_ctx_ref().round_clock;
+        if !clock.is_running() {
+            return;
+        }
+
+        let elapsed_sec = clock.elapsed().as_secs();
+        if self.reported_round_time_sec == Some(elapsed_sec) {
+            return;
+        }
+        self.reported_round_time_sec = Some(elapsed_sec);
+        emit_round_time(clock.into());
+    }
+
+    fn ensure_ticker_started(&mut self) {
+        if self.ticker_thread_handle.is_some() {
+            log::debug!("Game ticker already started");
+            return;
+        }
+
+        if current_session().clock().is_simulated() {
+            log::debug!("Simulated game is ticked along with its clock");
+            return;
+        }
+
+        log::info!("Starting game ticker thread");
+        let handle = spawn_in_session(run_game_ticker);
+        self.ticker_thread_handle = Some(handle);
+    }
+}
+
+fn run_game_ticker() {
+    while !current_session().is_closed() {
+        sleep(Duration::from_millis(TICK_INTERVAL_MS));
+        match try_game_mut() {
+            Some(mut game) => game.on_tick(),
+            None => log::trace!("Game is busy. Skipping the tick"),
+        }
+    }
+}
+
 #[allow(dead_code)]
 fn create_temp_directory() -> error_stack::Result<Arc<TempDir>, io::Error> {
     let tmp_dir = TempDir::new()?;
@@ -273,8 +1242,80 @@ fn create_temp_directory() -> error_stack::Result<Arc<TempDir>, io::Error> {
 impl GameController {
     /// This method should be used for every state change to ensure event emission
     pub fn set_game_state(&mut self, state: GameState) {
+        let before = JournalSnapshot::of(&self.game_state);
         self.game_state = state;
+        self.sync_round_clock();
+        self.sync_countdown();
         emit_game_state(&self.game_state);
+        self.autosave();
+        self.record_to_journal(JournalAction::Transition, &before);
+    }
+
+    /// Replayed and simulated games leave no saved games, journals or results behind
+    fn is_persisted(&self) -> bool {
+        !self.is_replaying && !current_session().clock().is_simulated()
+    }
+
+    /// Saves the game in progress so it can be resumed after the app restart
+    fn autosave(&self) {
+        if !self.is_persisted() {
+            return;
+        }
+        if let GameState::SetupAndLoading(_) = self.game_state {
+            return;
+        }
+
+        let game = self.game_state.game_ctx_ref();
+        let saved_game = SavedGame {
+            pack_path: self.game_pack.path.clone(),
+            pack_hash: self.game_pack.hash.clone(),
+            state_name: self.game_state.name().to_owned(),
+            game: Cow::Borrowed(game),
+            pack_progress: game.pack_progress(),
+            journal_path: self.journal.as_ref().map(|j| j.path().clone()),
+        };
+        if let Err(e) = saved_game.write() {
+            log::error!("Can't save the game: {:?}", e);
+        }
+    }
+
+    /// Starts the countdown configured for the new state or drops the previous one
+    fn sync_countdown(&mut self) {
+        let game_mode = &self.game_state.game_ctx_ref().game_mode;
+        let countdown = match &self.game_state {
+            GameState::WaitingForAnswerRequests(_) => game_mode
+                .buzz_window
+                .map(|d| Countdown::start(CountdownKind::BuzzWindow, d)),
+            GameState::AnswerAttemptReceived(_) => game_mode
+                .answer_time_limit
+                .map(|d| Countdown::start(CountdownKind::Answer, d)),
+            GameState::PickFirstQuestionChooser(_) => game_mode
+                .first_chooser_timeout
+                .map(|d| Countdown::start(CountdownKind::FirstChooserPick, d)),
+            GameState::ChooseQuestion(_) => game_mode
+                .question_chooser_timeout
+                .map(|d| Countdown::start(CountdownKind::QuestionChoice, d)),
+            _ => None,
+        };
+
+        let data = self.game_state.game_mut();
+        if data.countdown.is_none() && countdown.is_none() {
+            return;
+        }
+        self.reported_countdown_sec = None;
+        emit_countdown(countdown.as_ref().into());
+        data.countdown = countdown;
+    }
+
+    fn sync_round_clock(&mut self) {
+        let is_running = self.game_state.is_round_clock_running() && !self.is_replaying;
+        let clock = &mut self.game_state.game_mut().round_clock;
+        if is_running {
+            clock.resume();
+        } else {
+            clock.pause();
+        }
+        emit_round_time((&*clock).into());
     }
 
     fn emit_game_context(&self) {
@@ -290,6 +1331,15 @@ impl GameController {
         emit_game_state(&self.game_state);
     }
 
+    fn finish_auction_if_decided(&mut self) -> error_stack::Result<(), GameplayError> {
+        let ctx = get_ctx_ensuring_state!(self, AuctionBidding);
+
+        if ctx.is_auction_decided() {
+            self.finish_auction()?;
+        }
+        Ok(())
+    }
+
     fn handle_state_mismatch_error(&mut self, expected_state: &str) -> GameplayError {
         let state_mismatch = self.game_state.show_state_mismatch(expected_state);
         emit_error(format!("Context retrieval failure: {}", state_mismatch));
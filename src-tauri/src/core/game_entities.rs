@@ -1,6 +1,8 @@
+use crate::core::game::reaction_stats::ReactionStats;
+use crate::core::game::scoring_rules::ScoringRules;
 use crate::core::game_entities::GameplayError::PackElementNotPresent;
 use crate::core::game_entities::HubStatus::Detected;
-use crate::core::game_pack::pack_content_entities::Question;
+use crate::core::session::SessionError;
 use crate::player_server::entities::PsPlayer;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -26,6 +28,7 @@ pub struct PlayerStats {
     pub answered_correctly: i32,
     pub answered_wrong: i32,
     pub total_tries: i32,
+    pub reactions: ReactionStats,
 }
 
 #[derive(Debug, Default, Eq, Clone, PartialEq, Serialize, Deserialize)]
@@ -78,18 +81,19 @@ impl Player {
         self.state != PlayerState::Dead && self.state != PlayerState::Inactive
     }
 
-    pub fn answered_correctly(&mut self, question: &Question) {
+    /// `price` is the price of the question or the stake of the player for the auction
+    pub fn answered_correctly(&mut self, price: i32) {
         self.state = PlayerState::AnsweredCorrectly;
         self.stats.answered_correctly += 1;
         self.stats.total_tries += 1;
-        self.stats.score += question.price;
+        self.stats.score += price;
     }
 
-    pub fn answered_wrong(&mut self, question: &Question) {
+    pub fn answered_wrong(&mut self, price: i32, rules: &ScoringRules) {
         self.state = PlayerState::AnsweredWrong;
         self.stats.answered_wrong += 1;
         self.stats.total_tries += 1;
-        self.stats.score -= question.price;
+        self.stats.score = rules.score_after_wrong_answer(self.stats.score, price);
     }
 }
 
@@ -130,7 +134,7 @@ pub enum GameplayError {
     #[error("{0}")]
     PackElementNotPresent(GamePackError),
     #[error("Player {0} not present")]
-    PlayerNotPresent(u8),
+    PlayerNotPresent(i32),
     #[error("HUB operation failed")]
     HubOperationError,
     #[error("Answer forbidden")]
@@ -143,6 +147,30 @@ pub enum GameplayError {
     BrokenHubConnection,
     #[error("Player not found")]
     PlayerNotFound,
+    #[error("Terminal {0} is taken")]
+    TerminalTaken(u8),
+    #[error("Invalid auction bid")]
+    InvalidBid,
+    #[error("Invalid final round wager")]
+    InvalidWager,
+    #[error("Nothing to undo")]
+    NothingToUndo,
+    #[error("Can't resume the saved game")]
+    ResumeFailed,
+    #[error("Invalid team setup")]
+    InvalidTeam,
+    #[error("Question chooser is not picked")]
+    NoQuestionChooser,
+    #[error("Can't replay the game journal")]
+    ReplayFailed,
+    #[error("Replay speed must be a positive number")]
+    InvalidReplaySpeed,
+    #[error("Can't access player profiles")]
+    ProfileStoreFailed,
+    #[error("Can't access the tournament")]
+    TournamentFailed,
+    #[error("{0}")]
+    Session(SessionError),
 }
 
 impl From<GamePackError> for GameplayError {
@@ -150,3 +178,9 @@ impl From<GamePackError> for GameplayError {
         PackElementNotPresent(value)
     }
 }
+
+impl From<SessionError> for GameplayError {
+    fn from(value: SessionError) -> Self {
+        GameplayError::Session(value)
+    }
+}
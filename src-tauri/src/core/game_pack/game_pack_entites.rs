@@ -20,6 +20,10 @@ pub struct PackLocationData {
 
 #[derive(Default, Debug, Clone)]
 pub struct GamePack {
+    /// Path to the pack archive the game was loaded from
+    pub path: String,
+    /// Hash of the pack archive. Tells if the pack was changed since the game was saved
+    pub hash: String,
     pub location: PackLocationData,
     pub content: PackContent,
 }
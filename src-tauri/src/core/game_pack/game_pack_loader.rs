@@ -3,6 +3,7 @@ use crate::core::game_pack::pack_content_entities::{Atom, PackContent, QuestionM
 use crate::core::game_pack::pack_content_loader::load_pack_content;
 use error_stack::{bail, IntoReport, Report, report, Result, ResultExt};
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 use std::fs;
 use std::io::BufRead;
 use std::path::Path;
@@ -65,11 +66,25 @@ pub fn load_game_pack(game_archive_path: &str) -> Result<GamePack, GamePackLoadi
 
     // simplify_pack_assets_paths(&locations, &mut game_package)?;
     Ok(GamePack {
+        path: game_archive_path.to_owned(),
+        hash: calc_pack_hash(game_archive_path)?,
         location: locations,
         content: game_package,
     })
 }
 
+/// SHA-256 of the pack archive content. Stays the same between app and toolchain versions
+pub fn calc_pack_hash(game_archive_path: &str) -> Result<String, GamePackLoadingError> {
+    let content = fs::read(game_archive_path)
+        .into_report()
+        .attach_printable_lazy(|| format!("Can't read pack {game_archive_path}"))
+        .change_context(GamePackLoadingError::InvalidPathToPack(
+            game_archive_path.to_owned(),
+        ))?;
+
+    Ok(format!("{:x}", Sha256::digest(&content)))
+}
+
 impl QuestionMediaType {
     fn get_media_dir<'a>(&'a self, locations: &'a PackLocationData) -> Option<&Path> {
         match self {
@@ -1,5 +1,9 @@
 use crate::core::game_pack::pack_content_dto::{InfoDto, RightDto};
-use crate::core::game_pack::pack_content_entities::{Atom, AtomRole, Author, Info, PackContent, Question, QuestionMediaType, Round, Topic};
+use crate::core::game_pack::pack_content_entities::{
+    count_questions_of_type, Atom, AtomRole, Author, Info, PackContent, Question,
+    QuestionMediaType, Round, Topic,
+};
+use crate::host_api::dto::QuestionType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -30,8 +34,16 @@ pub(super) struct ScenarioDtoV4 {
     pub atoms_list: Vec<AtomDtoV4>,
 }
 
+//  <type name="cat">...</type>
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub(super) struct QuestionTypeDtoV4 {
+    pub name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub(super) struct QuestionDtoV4 {
+    #[serde(default)]
+    pub r#type: Option<QuestionTypeDtoV4>,
     pub scenario: ScenarioDtoV4,
     pub right: RightDto,
     pub price: i32,
@@ -109,6 +121,19 @@ impl From<&AtomDtoV4> for Atom {
         }
     }
 }
+impl From<&Option<QuestionTypeDtoV4>> for QuestionType {
+    fn from(value: &Option<QuestionTypeDtoV4>) -> Self {
+        let Some(question_type) = value else {
+            return QuestionType::Normal;
+        };
+        match question_type.name.as_str() {
+            "cat" | "bagcat" => QuestionType::PigInPoke,
+            "auction" => QuestionType::Auction,
+            _ => QuestionType::Normal,
+        }
+    }
+}
+
 impl From<(String, &QuestionDtoV4)> for Question {
     fn from(tuple: (String, &QuestionDtoV4)) -> Self {
         let (topic, q) = tuple;
@@ -145,8 +170,9 @@ impl From<(String, &QuestionDtoV4)> for Question {
             price: q.price,
             scenario,
             correct_answer,
-            question_type: Default::default(),
+            question_type: QuestionType::from(&q.r#type),
             is_used: false,
+            ..Default::default()
         }
     }
 }
@@ -179,14 +205,17 @@ impl From<&RoundDtoV4> for Round {
             .iter()
             .map(|&theme| theme.questions.len() as i32)
             .sum::<i32>();
+        let pip_question_count = count_questions_of_type(&topics, QuestionType::PigInPoke);
+        let auction_question_count = count_questions_of_type(&topics, QuestionType::Auction);
         Self {
             name: value.name.clone(),
             round_type: value.r#type.clone(),
             topics,
             question_count,
             questions_left: question_count,
-            normal_question_count: question_count,
-            pip_question_count: 0,
+            normal_question_count: question_count - pip_question_count - auction_question_count,
+            pip_question_count,
+            auction_question_count,
             round_stats: Default::default(),
         }
     }
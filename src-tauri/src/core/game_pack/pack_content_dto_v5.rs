@@ -1,5 +1,10 @@
 use crate::core::game_pack::pack_content_dto::{InfoDto, RightDto};
-use crate::core::game_pack::pack_content_entities::{Atom, AtomRole, Author, Info, PackContent, Question, QuestionMediaType, Round, Topic};
+use crate::core::game_pack::pack_content_entities::{
+    count_questions_of_type, AnswerType, Atom, AtomRole, Author, Info, PackContent,
+    PackQuestionType, PriceRange, Question, QuestionMediaType, QuestionParams, Round,
+    SelectionMode, Topic,
+};
+use crate::host_api::dto::QuestionType;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -31,7 +36,12 @@ pub struct ItemDtoV5 {
 #[allow(non_camel_case_types)]
 pub enum ParamTypeV5 {
     #[default]
+    simple,
     content,
+    group,
+    numberSet,
+    #[serde(other)]
+    unknown,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
@@ -40,15 +50,48 @@ pub enum ParamNameType {
     #[default]
     question,
     answer,
+    theme,
+    price,
+    selectionMode,
+    answerType,
+    answerOptions,
+    answerDeviation,
+    #[serde(other)]
+    unknown,
+}
+
+//  <numberSet minimum="100" maximum="500" step="100" />
+// Kept as text: the param value is only typed after its shape is matched
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub(super) struct NumberSetDtoV5 {
+    pub minimum: String,
+    pub maximum: String,
+    pub step: Option<String>,
+}
+
+//  <param name="A" type="content"><item>Option text</item></param>
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub(super) struct AnswerOptionDtoV5 {
+    pub name: String,
+}
+
+// Params of v5 hold either media items, plain text, number set or nested params
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(untagged)]
+pub(super) enum ParamValueDtoV5 {
+    NumberSet(NumberSetDtoV5),
+    AnswerOption(AnswerOptionDtoV5),
+    Item(ItemDtoV5),
+    Text(String),
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub(super) struct ParamDtoV5 {
     pub r#type: Option<ParamTypeV5>,
-    // #[serde(default = "String::default")]
     pub name: ParamNameType,
+    #[serde(default)]
     #[serde(rename = "$value")]
-    pub item: ItemDtoV5,
+    pub values: Vec<ParamValueDtoV5>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -59,6 +102,8 @@ pub(super) struct ParamsDtoV5 {
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub(super) struct QuestionDtoV5 {
+    #[serde(default)]
+    pub r#type: Option<String>,
     pub params: ParamsDtoV5,
     pub right: RightDto,
     pub price: i32,
@@ -125,11 +170,11 @@ pub(super) struct PackageDtoV5 {
     pub rounds: RoundsDtoV5,
 }
 
-impl From<&ParamDtoV5> for Atom {
-    fn from(value: &ParamDtoV5) -> Self {
+impl From<(&ItemDtoV5, AtomRole)> for Atom {
+    fn from((item, role): (&ItemDtoV5, AtomRole)) -> Self {
         Self {
             atom_type: {
-                match value.item.r#type {
+                match item.r#type {
                     ItemTypeDtoV4::say => QuestionMediaType::Text,
                     ItemTypeDtoV4::audio => QuestionMediaType::Voice,
                     ItemTypeDtoV4::video => QuestionMediaType::Video,
@@ -137,24 +182,128 @@ impl From<&ParamDtoV5> for Atom {
                     ItemTypeDtoV4::image => QuestionMediaType::Image,
                 }
             },
-            content: value.item.content.clone(),
-            role: match value.name {
-                ParamNameType::question => AtomRole::Question,
-                ParamNameType::answer => AtomRole::Answer
-            },
+            content: item.content.clone(),
+            role,
+        }
+    }
+}
+
+impl ParamDtoV5 {
+    fn atoms(&self, role: AtomRole) -> Vec<Atom> {
+        self.values
+            .iter()
+            .filter_map(|value| match value {
+                ParamValueDtoV5::Item(item) => Some(Atom::from((item, role.clone()))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn text(&self) -> Option<String> {
+        self.values.iter().find_map(|value| match value {
+            ParamValueDtoV5::Text(text) => Some(text.trim().to_string()),
+            ParamValueDtoV5::Item(item) => Some(item.content.trim().to_string()),
+            _ => None,
+        })
+    }
+
+    fn number(&self) -> Option<i32> {
+        self.text().and_then(|text| text.parse::<i32>().ok())
+    }
+
+    fn price_range(&self) -> Option<PriceRange> {
+        let number_set = self.values.iter().find_map(|value| match value {
+            ParamValueDtoV5::NumberSet(number_set) => Some(number_set),
+            _ => None,
+        });
+        match number_set {
+            Some(number_set) => number_set.price_range(),
+            // Single price given as text
+            None => self.number().map(|price| PriceRange {
+                min: price,
+                max: price,
+                step: 0,
+            }),
+        }
+    }
+
+    fn answer_options(&self) -> Vec<String> {
+        self.values
+            .iter()
+            .filter_map(|value| match value {
+                ParamValueDtoV5::AnswerOption(option) => Some(option.name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl NumberSetDtoV5 {
+    fn price_range(&self) -> Option<PriceRange> {
+        let parse = |value: &str| value.trim().parse::<i32>().ok();
+        let step = match &self.step {
+            Some(step) => parse(step)?,
+            None => 0,
+        };
+        Some(PriceRange {
+            min: parse(&self.minimum)?,
+            max: parse(&self.maximum)?,
+            step,
+        })
+    }
+}
+
+fn question_type_from_v5(question_type: &Option<String>) -> PackQuestionType {
+    match question_type.as_deref() {
+        None | Some("simple") => PackQuestionType::Simple,
+        Some("stake") => PackQuestionType::Stake,
+        Some("secret") => PackQuestionType::Secret,
+        Some("secretPublicPrice") => PackQuestionType::SecretPublicPrice,
+        Some("secretNoQuestion") => PackQuestionType::SecretNoQuestion,
+        Some("noRisk") => PackQuestionType::NoRisk,
+        Some("forAll") => PackQuestionType::ForAll,
+        Some(unknown) => PackQuestionType::Unknown(unknown.to_string()),
+    }
+}
+
+fn selection_mode_from_v5(mode: Option<String>) -> SelectionMode {
+    match mode.as_deref() {
+        Some("exceptCurrent") => SelectionMode::ExceptCurrent,
+        Some("any") | None => SelectionMode::Any,
+        Some(unknown) => {
+            log::warn!(
+                "Unknown selection mode '{}'. Any player can be selected",
+                unknown
+            );
+            SelectionMode::Any
         }
     }
 }
+
+fn answer_type_from_v5(answer_type: Option<String>) -> AnswerType {
+    match answer_type.as_deref() {
+        Some("text") | None => AnswerType::Text,
+        Some("select") => AnswerType::Select,
+        Some("number") => AnswerType::Number,
+        Some(other) => AnswerType::Other(other.to_string()),
+    }
+}
+
 impl From<(String, &QuestionDtoV5)> for Question {
     fn from(tuple: (String, &QuestionDtoV5)) -> Self {
         let (topic, q) = tuple;
 
-        let atoms = q.params
-            .params_list
-            .iter()
-            .map(Atom::from)
-            .collect::<Vec<Atom>>();
+        let pack_question_type = question_type_from_v5(&q.r#type);
+        if let PackQuestionType::Unknown(name) = &pack_question_type {
+            log::warn!(
+                "Question of '{}' for {} has unknown type '{}'. It's played as a simple one",
+                topic,
+                q.price,
+                name
+            );
+        }
 
+        let mut params = QuestionParams::default();
         let mut question_atoms = vec![];
         let mut answer_atoms = if q.right.answer.is_empty() {
             vec![]
@@ -165,25 +314,37 @@ impl From<(String, &QuestionDtoV5)> for Question {
                 role: AtomRole::Answer,
             }]
         };
-        atoms.iter().for_each(|a| {
-            match a.role {
-                AtomRole::Question => question_atoms.push(a.clone()),
-                AtomRole::Answer => answer_atoms.push(a.clone()),
-                AtomRole::Marker => {} // Skipping markers
+        for param in &q.params.params_list {
+            match param.name {
+                ParamNameType::question => question_atoms.extend(param.atoms(AtomRole::Question)),
+                ParamNameType::answer => answer_atoms.extend(param.atoms(AtomRole::Answer)),
+                ParamNameType::theme => params.theme = param.text(),
+                ParamNameType::price => {
+                    params.price_range = param.price_range();
+                    if params.price_range.is_none() {
+                        log::warn!("Price of '{}' for {} can't be parsed", topic, q.price);
+                    }
+                }
+                ParamNameType::selectionMode => {
+                    params.selection_mode = selection_mode_from_v5(param.text())
+                }
+                ParamNameType::answerType => params.answer_type = answer_type_from_v5(param.text()),
+                ParamNameType::answerOptions => params.answer_options = param.answer_options(),
+                ParamNameType::answerDeviation => params.answer_deviation = param.number(),
+                ParamNameType::unknown => {
+                    log::debug!("Skipping unknown param of '{}' for {}", topic, q.price)
+                }
             }
-            // if a.atom_type == QuestionMediaType::Marker {
-            //     answer_atoms.push(a.clone());
-            // } else {
-            //     question_atoms.push(a.clone());
-            // }
-        });
+        }
 
         Question {
             topic,
             price: q.price,
             scenario: question_atoms,
             correct_answer: answer_atoms,
-            question_type: Default::default(),
+            question_type: pack_question_type.question_type(),
+            pack_question_type,
+            params,
             is_used: false,
         }
     }
@@ -217,14 +378,17 @@ impl From<&RoundDtoV5> for Round {
             .iter()
             .map(|&theme| theme.questions.len() as i32)
             .sum::<i32>();
+        let pip_question_count = count_questions_of_type(&topics, QuestionType::PigInPoke);
+        let auction_question_count = count_questions_of_type(&topics, QuestionType::Auction);
         Self {
             name: value.name.clone(),
             round_type: value.r#type.clone(),
             topics,
             question_count,
             questions_left: question_count,
-            normal_question_count: question_count,
-            pip_question_count: 0,
+            normal_question_count: question_count - pip_question_count - auction_question_count,
+            pip_question_count,
+            auction_question_count,
             round_stats: Default::default(),
         }
     }
@@ -258,3 +422,137 @@ impl From<&PackageDtoV5> for PackContent {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_xml_rs::from_str;
+
+    fn question(question_type: &str, params: &str) -> Question {
+        let xml = format!(
+            r#"<question price="200" type="{}">
+                <params>{}</params>
+                <right><answer>Forty two</answer></right>
+            </question>"#,
+            question_type, params
+        );
+        let dto: QuestionDtoV5 = from_str(&xml).expect("Test");
+        Question::from(("Numbers".to_string(), &dto))
+    }
+
+    fn contents(atoms: &[Atom]) -> Vec<(QuestionMediaType, &str)> {
+        atoms
+            .iter()
+            .map(|a| (a.atom_type.clone(), a.content.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_price_is_parsed_from_number_set() {
+        let q = question(
+            "stake",
+            r#"<param name="question" type="content"><item>Guess the number</item></param>
+               <param name="price" type="numberSet">
+                   <numberSet minimum="100" maximum="500" step="100" />
+               </param>"#,
+        );
+
+        assert_eq!(q.question_type, QuestionType::Auction);
+        assert_eq!(
+            q.params.price_range,
+            Some(PriceRange {
+                min: 100,
+                max: 500,
+                step: 100,
+            })
+        );
+    }
+
+    #[test]
+    fn test_text_params_of_secret_question() {
+        let q = question(
+            "secret",
+            r#"<param name="question" type="content"><item>Guess the cat</item></param>
+               <param name="theme">Cats</param>
+               <param name="price">300</param>
+               <param name="selectionMode">exceptCurrent</param>"#,
+        );
+
+        assert_eq!(q.question_type, QuestionType::PigInPoke);
+        assert_eq!(q.params.theme.as_deref(), Some("Cats"));
+        assert_eq!(q.params.selection_mode, SelectionMode::ExceptCurrent);
+        assert_eq!(
+            q.params.price_range,
+            Some(PriceRange {
+                min: 300,
+                max: 300,
+                step: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_content_items_become_atoms() {
+        let q = question(
+            "simple",
+            r#"<param name="question" type="content">
+                   <item>Who is on the picture?</item>
+                   <item type="image" isRef="True">pony.png</item>
+               </param>
+               <param name="answer" type="content">
+                   <item type="audio" isRef="True">answer.mp3</item>
+               </param>"#,
+        );
+
+        assert_eq!(
+            contents(&q.scenario),
+            vec![
+                (QuestionMediaType::Text, "Who is on the picture?"),
+                (QuestionMediaType::Image, "pony.png"),
+            ]
+        );
+        assert_eq!(
+            contents(&q.correct_answer),
+            vec![
+                (QuestionMediaType::Text, "Forty two"),
+                (QuestionMediaType::Voice, "answer.mp3"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_answer_options_are_listed_by_their_labels() {
+        let q = question(
+            "simple",
+            r#"<param name="question" type="content"><item>Pick the color</item></param>
+               <param name="answerType">select</param>
+               <param name="answerOptions" type="group">
+                   <param name="A" type="content"><item>Red</item></param>
+                   <param name="B" type="content"><item>Blue</item></param>
+               </param>"#,
+        );
+
+        assert_eq!(q.params.answer_type, AnswerType::Select);
+        assert_eq!(q.params.answer_options, vec!["A", "B"]);
+    }
+
+    #[test]
+    fn test_unknown_type_and_params_are_played_as_simple_question() {
+        let q = question(
+            "mystery",
+            r#"<param name="question" type="content"><item>Guess</item></param>
+               <param name="hint" type="choice">Skip me</param>"#,
+        );
+
+        assert_eq!(
+            q.pack_question_type,
+            PackQuestionType::Unknown("mystery".to_string())
+        );
+        assert_eq!(q.question_type, QuestionType::Normal);
+        assert_eq!(q.params, QuestionParams::default());
+        assert_eq!(
+            contents(&q.scenario),
+            vec![(QuestionMediaType::Text, "Guess")]
+        );
+    }
+}
@@ -1,9 +1,9 @@
 use crate::host_api::dto::QuestionType;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 // Game entities
-#[derive(Debug, PartialEq, Clone, Serialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum QuestionMediaType {
     Text,
     Voice,
@@ -12,49 +12,119 @@ pub enum QuestionMediaType {
     Image,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum AtomRole {
     Question,
     Answer,
     Marker,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Atom {
     pub atom_type: QuestionMediaType,
     pub content: String,
     pub role: AtomRole
 }
 
-#[derive(Debug, PartialEq, Clone, Default)]
+/// Question type as the pack declares it. The game plays it as one of the `QuestionType`s
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub enum PackQuestionType {
+    #[default]
+    Simple,
+    Stake,
+    Secret,
+    SecretPublicPrice,
+    SecretNoQuestion,
+    NoRisk,
+    ForAll,
+    Unknown(String),
+}
+
+impl PackQuestionType {
+    pub fn question_type(&self) -> QuestionType {
+        match self {
+            PackQuestionType::Stake => QuestionType::Auction,
+            PackQuestionType::Secret
+            | PackQuestionType::SecretPublicPrice
+            | PackQuestionType::SecretNoQuestion => QuestionType::PigInPoke,
+            // Not supported by the gameplay yet
+            PackQuestionType::NoRisk | PackQuestionType::ForAll => QuestionType::Normal,
+            PackQuestionType::Simple | PackQuestionType::Unknown(_) => QuestionType::Normal,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct PriceRange {
+    pub min: i32,
+    pub max: i32,
+    pub step: i32,
+}
+
+/// Who the secret question can be given to
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub enum SelectionMode {
+    #[default]
+    Any,
+    ExceptCurrent,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub enum AnswerType {
+    #[default]
+    Text,
+    Select,
+    Number,
+    Other(String),
+}
+
+/// Parameters of the question type given by the pack
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct QuestionParams {
+    /// Topic announced instead of the original one
+    pub theme: Option<String>,
+    /// Prices to choose from for the secret and stake questions
+    pub price_range: Option<PriceRange>,
+    pub selection_mode: SelectionMode,
+    pub answer_type: AnswerType,
+    pub answer_options: Vec<String>,
+    pub answer_deviation: Option<i32>,
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct Question {
     pub topic: String,
     pub scenario: Vec<Atom>,
     pub correct_answer: Vec<Atom>,
     pub question_type: QuestionType,
+    #[serde(default)]
+    pub pack_question_type: PackQuestionType,
+    #[serde(default)]
+    pub params: QuestionParams,
     pub price: i32,
     pub is_used: bool,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Topic {
     pub name: String,
     pub questions: HashMap<i32, Question>,
 }
 
 ///// LEGACY
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct RoundStats {
     pub questions_played: i32,
     pub normal_questions_played: i32,
     pub pip_questions_played: i32,
+    pub auction_questions_played: i32,
     pub total_correct_answers: i32,
     pub total_wrong_answers: i32,
     pub total_tries: i32,
     pub round_time: String,
 }
 
-#[derive(Debug, PartialEq, Clone, Default)]
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
 pub struct Round {
     pub name: String,
     pub round_type: String,
@@ -62,6 +132,7 @@ pub struct Round {
     pub question_count: i32,
     pub normal_question_count: i32,
     pub pip_question_count: i32,
+    pub auction_question_count: i32,
     pub questions_left: i32,
     pub round_stats: RoundStats,
 }
@@ -70,20 +141,35 @@ impl Round {
     pub fn is_round_over(&self) -> bool {
         self.questions_left == 0
     }
+
+    pub fn is_final(&self) -> bool {
+        self.round_type == "final"
+    }
+}
+
+pub fn count_questions_of_type(
+    topics: &HashMap<String, Topic>,
+    question_type: QuestionType,
+) -> i32 {
+    topics
+        .values()
+        .flat_map(|topic| topic.questions.values())
+        .filter(|q| q.question_type == question_type)
+        .count() as i32
 }
 
 // Pack information
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Author {
     pub name: String,
 }
 
-#[derive(Debug, Default, PartialEq, Clone)]
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Info {
     pub authors: Vec<Author>,
 }
 
-#[derive(Debug, Default, PartialEq, Clone)]
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
 pub struct PackContent {
     pub name: String,
     pub version: String,
@@ -2,3 +2,5 @@ pub mod game;
 pub mod game_controller;
 pub mod game_entities;
 pub mod game_pack;
+pub mod session;
+pub mod simulation;
@@ -0,0 +1,332 @@
+use crate::core::game::clock::GameClock;
+use crate::core::game_controller::GameController;
+use crate::host_api::event_sink::unsubscribe_session;
+use crate::player_server::player_server::PlayerServer;
+use serde::Serialize;
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock, RwLockReadGuard};
+use std::thread;
+use std::thread::JoinHandle;
+
+/// Session used when no session id is given. Always open
+pub const DEFAULT_SESSION_ID: &str = "default";
+/// Session id becomes part of the file names, so it's kept short and path-safe
+const MAX_SESSION_ID_LEN: usize = 32;
+/// Sessions besides the default one which can exist at once
+pub const MAX_SESSIONS: usize = 16;
+
+#[derive(Debug, Clone, Serialize, thiserror::Error)]
+pub enum SessionError {
+    #[error("Session '{0}' not found")]
+    NotFound(String),
+    #[error("Session '{0}' already exists")]
+    AlreadyExists(String),
+    #[error("Default session can't be closed")]
+    DefaultSessionClosed,
+    #[error("Session id '{0}' must be 1 to 32 letters, digits, '_' or '-'")]
+    InvalidId(String),
+    #[error("No more than {} sessions can be open. Close one first", MAX_SESSIONS)]
+    TooManySessions,
+}
+
+/// Game played on its own stage with its own player server and hub.
+/// Background threads keep references to the session, so it lives as long as the app.
+/// The game and player server of the closed session are dropped, and its slot is taken by
+/// the next opened session once the threads stop. So there are at most `MAX_SESSIONS` slots
+pub struct Session {
+    id: RwLock<String>,
+    game: RwLock<GameController>,
+    player_server: RwLock<PlayerServer>,
+    clock: GameClock,
+    is_closed: AtomicBool,
+    /// Threads spawned with `spawn_in_session` which are still running
+    running_threads: AtomicUsize,
+}
+
+impl Session {
+    fn new(id: &str) -> &'static Self {
+        Box::leak(Box::new(Self {
+            id: RwLock::new(id.to_string()),
+            game: RwLock::new(GameController::default()),
+            player_server: RwLock::new(PlayerServer::default()),
+            clock: GameClock::default(),
+            is_closed: AtomicBool::new(false),
+            running_threads: AtomicUsize::new(0),
+        }))
+    }
+
+    pub fn id(&self) -> String {
+        self.id.read().expect("Mutex is poisoned").clone()
+    }
+
+    pub fn game(&self) -> &RwLock<GameController> {
+        &self.game
+    }
+
+    pub fn player_server(&self) -> &RwLock<PlayerServer> {
+        &self.player_server
+    }
+
+    pub fn clock(&self) -> &GameClock {
+        &self.clock
+    }
+
+    /// Background threads of the closed session stop
+    pub fn is_closed(&self) -> bool {
+        self.is_closed.load(Ordering::SeqCst)
+    }
+
+    /// Files of the default session keep their names, so the games saved before sessions resume
+    pub fn file_name(&self, name: &str, extension: &str) -> String {
+        let id = self.id();
+        if id == DEFAULT_SESSION_ID {
+            format!("{}.{}", name, extension)
+        } else {
+            format!("{}_{}.{}", name, id, extension)
+        }
+    }
+
+    /// Slot is free when the session is closed and none of its threads works with it anymore
+    fn is_free(&self) -> bool {
+        self.is_closed() && self.running_threads.load(Ordering::SeqCst) == 0
+    }
+
+    fn reopen(&self, id: &str) {
+        *self.id.write().expect("Mutex is poisoned") = id.to_string();
+        self.clock.reset();
+        self.is_closed.store(false, Ordering::SeqCst);
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref DEFAULT_SESSION: &'static Session = Session::new(DEFAULT_SESSION_ID);
+    static ref SESSIONS: RwLock<HashMap<String, &'static Session>> = RwLock::new(HashMap::new());
+    /// Every session ever opened besides the default one. Closed ones are reused
+    static ref SLOTS: Mutex<Vec<&'static Session>> = Mutex::new(vec![]);
+}
+
+thread_local! {
+    static CURRENT_SESSION: Cell<Option<&'static Session>> = Cell::new(None);
+}
+
+/// Session the calls of this thread are routed to
+pub fn current_session() -> &'static Session {
+    CURRENT_SESSION
+        .with(Cell::get)
+        .unwrap_or_else(|| *DEFAULT_SESSION)
+}
+
+pub fn find_session(id: &str) -> Result<&'static Session, SessionError> {
+    if id == DEFAULT_SESSION_ID {
+        return Ok(*DEFAULT_SESSION);
+    }
+    sessions()
+        .get(id)
+        .copied()
+        .ok_or_else(|| SessionError::NotFound(id.to_string()))
+}
+
+pub fn open_session(id: &str) -> Result<(), SessionError> {
+    if !is_valid_id(id) {
+        return Err(SessionError::InvalidId(id.to_string()));
+    }
+    let mut sessions = SESSIONS.write().expect("Mutex is poisoned");
+    if id == DEFAULT_SESSION_ID || sessions.contains_key(id) {
+        return Err(SessionError::AlreadyExists(id.to_string()));
+    }
+
+    log::info!("Opening session '{}'", id);
+    sessions.insert(id.to_string(), take_slot(id)?);
+    Ok(())
+}
+
+fn take_slot(id: &str) -> Result<&'static Session, SessionError> {
+    let mut slots = SLOTS.lock().expect("Mutex is poisoned");
+    if let Some(slot) = slots.iter().find(|s| s.is_free()) {
+        log::debug!("Session '{}' takes the slot of closed '{}'", id, slot.id());
+        slot.reopen(id);
+        return Ok(slot);
+    }
+    if slots.len() >= MAX_SESSIONS {
+        return Err(SessionError::TooManySessions);
+    }
+    let session = Session::new(id);
+    slots.push(session);
+    Ok(session)
+}
+
+/// Resets the game and releases the hub of the session
+pub fn close_session(id: &str) -> Result<(), SessionError> {
+    if id == DEFAULT_SESSION_ID {
+        return Err(SessionError::DefaultSessionClosed);
+    }
+    let session = SESSIONS
+        .write()
+        .expect("Mutex is poisoned")
+        .remove(id)
+        .ok_or_else(|| SessionError::NotFound(id.to_string()))?;
+
+    log::info!("Closing session '{}'", id);
+    // The slot isn't reused until the session is cleaned up
+    let _slots = SLOTS.lock().expect("Mutex is poisoned");
+    session.is_closed.store(true, Ordering::SeqCst);
+    let _guard = enter(session);
+    {
+        let mut game = session.game.write().expect("Mutex is poisoned");
+        game.reset_the_game();
+        *game = GameController::default();
+    }
+    {
+        let mut player_server = session.player_server.write().expect("Mutex is poisoned");
+        player_server.drop_hub();
+        *player_server = PlayerServer::default();
+    }
+    unsubscribe_session(id);
+    Ok(())
+}
+
+/// Default session goes first
+pub fn session_ids() -> Vec<String> {
+    let mut ids: Vec<String> = sessions().keys().cloned().collect();
+    ids.sort();
+    ids.insert(0, DEFAULT_SESSION_ID.to_string());
+    ids
+}
+
+/// Routes the calls of this thread to the session until the guard is dropped
+pub struct SessionGuard {
+    previous: Option<&'static Session>,
+}
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        CURRENT_SESSION.with(|current| current.set(self.previous));
+    }
+}
+
+/// Session of the host command. Commands without the session id go to the default one
+pub fn enter_session(id: Option<&str>) -> Result<SessionGuard, SessionError> {
+    let session = find_session(id.unwrap_or(DEFAULT_SESSION_ID)).map_err(|e| {
+        log::error!("Can't route the command: {}", e);
+        e
+    })?;
+    Ok(enter(session))
+}
+
+/// Spawns the thread working with the session of the current thread
+pub fn spawn_in_session<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let session = current_session();
+    session.running_threads.fetch_add(1, Ordering::SeqCst);
+    thread::spawn(move || {
+        let _guard = enter(session);
+        let _running = RunningThread(session);
+        f()
+    })
+}
+
+/// Counts the thread as running until it's dropped, even if the thread panics
+struct RunningThread(&'static Session);
+
+impl Drop for RunningThread {
+    fn drop(&mut self) {
+        self.0.running_threads.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn enter(session: &'static Session) -> SessionGuard {
+    SessionGuard {
+        previous: CURRENT_SESSION.with(|current| current.replace(Some(session))),
+    }
+}
+
+fn is_valid_id(id: &str) -> bool {
+    (1..=MAX_SESSION_ID_LEN).contains(&id.len())
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn sessions() -> RwLockReadGuard<'static, HashMap<String, &'static Session>> {
+    SESSIONS.read().expect("Mutex is poisoned")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    /// Sessions are shared by the tests running in parallel, so every test opens its own
+    fn open_unique_session(name: &str) -> String {
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = format!("test-{}-{}", name, NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        open_session(&id).expect("Test");
+        id
+    }
+
+    #[test]
+    fn test_commands_are_routed_to_the_session() {
+        let id = open_unique_session("routed");
+        assert!(open_session(&id).is_err());
+
+        {
+            let _guard = enter_session(Some(&id)).expect("Test");
+            assert_eq!(current_session().id(), id);
+            let spawned_id = spawn_in_session(|| current_session().id());
+            assert_eq!(spawned_id.join().expect("Test"), id);
+        }
+        assert_eq!(current_session().id(), DEFAULT_SESSION_ID);
+        assert!(enter_session(Some(&format!("{}-unknown", id))).is_err());
+        close_session(&id).expect("Test");
+    }
+
+    #[test]
+    fn test_session_id_is_path_safe() {
+        for id in ["", "../stage", "stage/1", "stage 1", &"s".repeat(33)] {
+            assert!(matches!(open_session(id), Err(SessionError::InvalidId(_))));
+        }
+        assert!(is_valid_id("Stage_1-a"));
+    }
+
+    #[test]
+    fn test_closed_session_is_not_found() {
+        let id = open_unique_session("closed");
+        let session = find_session(&id).expect("Test");
+        assert_eq!(
+            session.file_name("saved_game", "json"),
+            format!("saved_game_{}.json", id)
+        );
+
+        close_session(&id).expect("Test");
+        assert!(find_session(&id).is_err());
+        assert!(close_session(&id).is_err());
+        assert!(close_session(DEFAULT_SESSION_ID).is_err());
+        assert!(!session_ids().contains(&id));
+    }
+
+    #[test]
+    fn test_slot_of_closed_session_is_freed_when_its_threads_stop() {
+        let id = open_unique_session("slot");
+        let session = find_session(&id).expect("Test");
+        let (stop, stopped) = mpsc::channel::<()>();
+        let thread = {
+            let _guard = enter_session(Some(&id)).expect("Test");
+            spawn_in_session(move || stopped.recv())
+        };
+
+        close_session(&id).expect("Test");
+        assert!(session.is_closed());
+        assert!(!session.is_free());
+
+        // Keeps other tests from taking the slot until it's checked
+        let _slots = SLOTS.lock().expect("Test");
+        stop.send(()).expect("Test");
+        thread.join().expect("Test").expect("Test");
+        assert!(session.is_free());
+    }
+}
@@ -0,0 +1,392 @@
+use crate::core::game::journal::{HostCommand, JournalSnapshot};
+use crate::core::game_controller::{game, game_mut};
+use crate::core::game_entities::GameplayError;
+use crate::core::game_pack::game_pack_entites::GamePack;
+use crate::core::game_pack::pack_content_entities::{
+    count_questions_of_type, Atom, AtomRole, PackContent, Question, QuestionMediaType, Round, Topic,
+};
+use crate::core::session::{
+    close_session, current_session, enter_session, open_session, SessionGuard,
+};
+use crate::host_api::dto::QuestionType;
+use crate::host_api::event_sink::{subscribe, RecordingSink};
+use crate::hub::hub_api::{HubType, PlayerEvent, TermButtonState};
+use crate::player_server::player_server::ps_mut;
+use error_stack::Result;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+static NEXT_SIMULATION_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Game played in its own session on the simulated clock. Nothing happens by itself:
+/// the script gives the host commands, presses the buttons and advances the time.
+/// Events emitted by the game are recorded, so the script can check them afterwards
+pub struct Simulation {
+    session_id: String,
+    events: RecordingSink,
+    _session: SessionGuard,
+}
+
+impl Simulation {
+    /// Calls of this thread go to the simulated game until it's dropped
+    pub fn new(pack: GamePack) -> Self {
+        let id = NEXT_SIMULATION_ID.fetch_add(1, Ordering::Relaxed);
+        let session_id = format!("simulation-{}", id);
+        open_session(&session_id).expect("Expected unique simulation session id");
+        let session = enter_session(Some(&session_id)).expect("Expected opened session");
+        current_session().clock().simulate();
+
+        let events = RecordingSink::default();
+        subscribe(Box::new(events.clone()));
+        // Simulated game has no buzzers to light up
+        ps_mut().select_hub_type(HubType::Manual);
+        game_mut().set_game_pack(pack);
+
+        Self {
+            session_id,
+            events,
+            _session: session,
+        }
+    }
+
+    pub fn host(&self, command: HostCommand) -> Result<(), GameplayError> {
+        game_mut().run_host_command(command)
+    }
+
+    /// Button press at the current simulated time
+    pub fn press(&self, term_id: u8) {
+        let timestamp = current_session()
+            .clock()
+            .epoch_ms()
+            .expect("Simulated time is always known");
+        self.send_events(vec![PlayerEvent {
+            term_id,
+            timestamp,
+            state: TermButtonState::Pressed,
+        }]);
+    }
+
+    /// Delivers the events as if the hub has just read them. Timestamps are up to the script
+    pub fn send_events(&self, events: Vec<PlayerEvent>) {
+        game_mut().push_events(events);
+    }
+
+    /// Moves the time forward and lets the timers of the game react
+    pub fn advance(&self, duration: Duration) {
+        current_session().clock().advance(duration);
+        game_mut().on_tick();
+    }
+
+    pub fn state_name(&self) -> String {
+        game().game_state.name().to_string()
+    }
+
+    pub fn scores(&self) -> BTreeMap<u8, i32> {
+        JournalSnapshot::scores(game().game_state.game_ctx_ref())
+    }
+
+    pub fn events(&self) -> &RecordingSink {
+        &self.events
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        if let Err(e) = close_session(&self.session_id) {
+            log::error!("Can't close the simulation session: {}", e);
+        }
+    }
+}
+
+/// Builds the pack in memory, so games can be simulated without the pack archives.
+/// Topics go to the round added last
+#[derive(Debug, Default)]
+pub struct PackBuilder {
+    content: PackContent,
+}
+
+impl PackBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            content: PackContent {
+                name: name.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn round(self, name: &str) -> Self {
+        self.add_round(name, "")
+    }
+
+    pub fn final_round(self, name: &str) -> Self {
+        self.add_round(name, "final")
+    }
+
+    /// Topic of normal questions with the given prices
+    pub fn topic(self, name: &str, prices: &[i32]) -> Self {
+        prices.iter().fold(self, |builder, &price| {
+            builder.question(name, price, QuestionType::Normal)
+        })
+    }
+
+    pub fn question(mut self, topic: &str, price: i32, question_type: QuestionType) -> Self {
+        let round = self
+            .content
+            .rounds
+            .last_mut()
+            .expect("Expected the round to be added before its topics");
+        let entry = round
+            .topics
+            .entry(topic.to_string())
+            .or_insert_with(|| Topic {
+                name: topic.to_string(),
+                questions: HashMap::new(),
+            });
+
+        let text = |content: String, role: AtomRole| Atom {
+            atom_type: QuestionMediaType::Text,
+            content,
+            role,
+        };
+        entry.questions.insert(
+            price,
+            Question {
+                topic: topic.to_string(),
+                scenario: vec![text(
+                    format!("Question of '{}' for {}", topic, price),
+                    AtomRole::Question,
+                )],
+                correct_answer: vec![text(
+                    format!("Answer of '{}' for {}", topic, price),
+                    AtomRole::Answer,
+                )],
+                question_type,
+                price,
+                is_used: false,
+                ..Default::default()
+            },
+        );
+        self
+    }
+
+    pub fn build(mut self) -> GamePack {
+        for round in &mut self.content.rounds {
+            let question_count = round
+                .topics
+                .values()
+                .map(|t| t.questions.len() as i32)
+                .sum();
+            let pip_question_count =
+                count_questions_of_type(&round.topics, QuestionType::PigInPoke);
+            let auction_question_count =
+                count_questions_of_type(&round.topics, QuestionType::Auction);
+
+            round.question_count = question_count;
+            round.questions_left = question_count;
+            round.normal_question_count =
+                question_count - pip_question_count - auction_question_count;
+            round.pip_question_count = pip_question_count;
+            round.auction_question_count = auction_question_count;
+        }
+
+        GamePack {
+            path: format!("{}.siq", self.content.name),
+            content: self.content,
+            ..Default::default()
+        }
+    }
+
+    fn add_round(mut self, name: &str, round_type: &str) -> Self {
+        self.content.rounds.push(Round {
+            name: name.to_string(),
+            round_type: round_type.to_string(),
+            ..Default::default()
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::game_data::GameMode;
+    use crate::core::game::journal::{buzzes_of, JournalAction};
+
+    fn select(topic: &str, price: i32) -> HostCommand {
+        HostCommand::SelectQuestion {
+            topic: topic.to_string(),
+            price,
+        }
+    }
+
+    fn answer(answered_correctly: bool) -> HostCommand {
+        HostCommand::AnswerQuestion { answered_correctly }
+    }
+
+    fn add_players(sim: &Simulation, players: &[(u8, &str)]) {
+        for (id, name) in players {
+            let add = HostCommand::AddPlayer {
+                player_id: *id,
+                name: name.to_string(),
+            };
+            sim.host(add).expect("Test");
+        }
+    }
+
+    #[test]
+    fn test_game_is_played_from_setup_to_the_end() {
+        let pack = PackBuilder::new("Simulated")
+            .round("First round")
+            .topic("Rivers", &[100, 200])
+            .round("Second round")
+            .topic("Lakes", &[300])
+            .build();
+        assert_eq!(pack.content.rounds[0].questions_left, 2);
+
+        let sim = Simulation::new(pack);
+        for (id, name) in [(1, "Alice"), (2, "Bob"), (3, "Carol")] {
+            let name = name.to_string();
+            let add = HostCommand::AddPlayer {
+                player_id: id,
+                name,
+            };
+            sim.host(add).expect("Test");
+        }
+        let game_mode = GameMode {
+            buzz_window: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        sim.host(HostCommand::StartNewGame { game_mode })
+            .expect("Test");
+        assert_eq!(sim.state_name(), "PickFirstQuestionChooser");
+
+        sim.press(2);
+        sim.host(select("Rivers", 100)).expect("Test");
+        sim.host(HostCommand::AllowAnswer).expect("Test");
+        sim.advance(Duration::from_millis(300));
+        sim.press(1);
+        assert_eq!(sim.state_name(), "AnswerAttemptReceived");
+        sim.host(answer(true)).expect("Test");
+        sim.host(HostCommand::FinishQuestion).expect("Test");
+
+        sim.host(select("Rivers", 200)).expect("Test");
+        sim.host(HostCommand::AllowAnswer).expect("Test");
+        sim.press(3);
+        sim.host(answer(false)).expect("Test");
+        sim.host(HostCommand::AllowAnswer).expect("Test");
+        sim.press(2);
+        sim.host(answer(true)).expect("Test");
+        sim.host(HostCommand::FinishQuestion).expect("Test");
+        assert_eq!(sim.state_name(), "ShowRoundStats");
+
+        sim.host(HostCommand::InitNextRound).expect("Test");
+        sim.press(1);
+        sim.host(select("Lakes", 300)).expect("Test");
+        sim.host(HostCommand::AllowAnswer).expect("Test");
+        sim.advance(Duration::from_secs(5));
+        assert_eq!(sim.state_name(), "EndQuestion");
+        sim.host(HostCommand::FinishQuestion).expect("Test");
+        sim.host(HostCommand::InitNextRound).expect("Test");
+
+        assert_eq!(sim.state_name(), "EndTheGame");
+        assert_eq!(
+            sim.scores(),
+            BTreeMap::from([(1, 100), (2, 200), (3, -200)])
+        );
+        let final_results = sim.events().payloads_of("FinalResults");
+        assert_eq!(final_results.len(), 1);
+        assert_eq!(final_results[0]["endGameReason"], "AllRoundsPlayed");
+        assert_eq!(final_results[0]["first"]["name"], "Bob");
+    }
+
+    /// Alice and Bob are asked the question. Each batch is delivered once the answer is allowed.
+    /// Returns the scores after the host accepts the answer
+    fn play_batches(batches: impl Fn(u32) -> Vec<Vec<PlayerEvent>>) -> BTreeMap<u8, i32> {
+        let pack = PackBuilder::new("Simulated")
+            .round("First round")
+            .topic("Rivers", &[100])
+            .build();
+        let sim = Simulation::new(pack);
+        add_players(&sim, &[(1, "Alice"), (2, "Bob")]);
+        let game_mode = GameMode::default();
+        sim.host(HostCommand::StartNewGame { game_mode })
+            .expect("Test");
+        sim.press(2);
+        sim.host(select("Rivers", 100)).expect("Test");
+        sim.host(HostCommand::AllowAnswer).expect("Test");
+        let answer_allowed_at = game().game_state.game_ctx_ref().allow_answer_timestamp;
+        sim.advance(Duration::from_millis(500));
+        batches(answer_allowed_at)
+            .into_iter()
+            .for_each(|batch| sim.send_events(batch));
+        assert_eq!(sim.state_name(), "AnswerAttemptReceived");
+        sim.host(answer(true)).expect("Test");
+        sim.scores()
+    }
+
+    fn pressed(term_id: u8, timestamp: u32) -> PlayerEvent {
+        PlayerEvent {
+            term_id,
+            timestamp,
+            state: TermButtonState::Pressed,
+        }
+    }
+
+    #[test]
+    fn test_replay_of_a_batch_gives_the_answer_to_the_fastest_press() {
+        // Hub read Bob's terminal first, though Alice pressed earlier
+        let batch =
+            |allowed_at: u32| vec![pressed(2, allowed_at + 300), pressed(1, allowed_at + 100)];
+
+        let live = play_batches(|allowed_at| vec![batch(allowed_at)]);
+        assert_eq!(live, BTreeMap::from([(1, 100), (2, 0)]));
+
+        // Replay delivers every journaled press on its own
+        let replayed = play_batches(|allowed_at| {
+            buzzes_of(&batch(allowed_at), allowed_at)
+                .into_iter()
+                .map(|buzz| match buzz {
+                    JournalAction::Buzz {
+                        term_id, timestamp, ..
+                    } => vec![pressed(term_id, timestamp)],
+                    other => panic!("Expected buzz, got {:?}", other),
+                })
+                .collect()
+        });
+        assert_eq!(replayed, live);
+    }
+
+    #[test]
+    fn test_random_question_is_played_when_nobody_chooses_in_time() {
+        let pack = PackBuilder::new("Simulated")
+            .round("First round")
+            .topic("Rivers", &[100, 200])
+            .build();
+        let sim = Simulation::new(pack);
+        add_players(&sim, &[(1, "Alice"), (2, "Bob")]);
+        let game_mode = GameMode {
+            question_chooser_timeout: Some(Duration::from_secs(10)),
+            ..Default::default()
+        };
+        sim.host(HostCommand::StartNewGame { game_mode })
+            .expect("Test");
+        sim.press(1);
+        assert_eq!(sim.state_name(), "ChooseQuestion");
+
+        sim.advance(Duration::from_secs(10));
+        assert_eq!(sim.state_name(), "ChooseQuestion");
+        assert_eq!(
+            game().game_state.game_ctx_ref().question_chooser_id,
+            Some(2)
+        );
+
+        sim.advance(Duration::from_secs(10));
+        assert_eq!(sim.state_name(), "DisplayQuestion");
+        assert_eq!(
+            game().game_state.game_ctx_ref().current_question.topic,
+            "Rivers"
+        );
+    }
+}
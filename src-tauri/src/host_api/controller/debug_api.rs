@@ -1,4 +1,5 @@
 use crate::core::game_controller::game_mut;
+use crate::core::session::enter_session;
 use crate::host_api::dto::{HubRequestDto, HubResponseDto};
 use crate::hub::hub_api::{HubManager, HubManagerError};
 use crate::hub::hw::internal::api_types::{HwHubIoError, HwHubRequest};
@@ -11,20 +12,31 @@ use tauri::command;
 /// Tries to set game state
 /// May break the game completely
 #[command]
-pub fn dbg_set_game_state(name: String) {
+pub fn dbg_set_game_state(name: String, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     let mut app = game_mut();
     app._dbg_set_game_state(name);
 }
 
 #[command]
-pub fn dbg_reset_game() {
+pub fn dbg_reset_game(session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     let mut app = game_mut();
     app.reset_the_game();
 }
 
 /// HUB Debug API
 #[command]
-pub fn dbg_setup_hub_connection(port_name: String) -> Result<(), HubManagerError> {
+pub fn dbg_setup_hub_connection(
+    port_name: String,
+    session_id: Option<String>,
+) -> Result<(), HubManagerError> {
+    let _session =
+        enter_session(session_id.as_deref()).map_err(|_| HubManagerError::InternalError)?;
     log::info!("Trying to open HUB connection");
     let game_ctx = ps();
     let mut hub = game_ctx.hub_mut();
@@ -35,7 +47,11 @@ pub fn dbg_setup_hub_connection(port_name: String) -> Result<(), HubManagerError
 }
 
 #[command]
-pub fn dbg_send_raw_request_frame(request_frame: Vec<u8>) -> Result<Vec<u8>, HwHubIoError> {
+pub fn dbg_send_raw_request_frame(
+    request_frame: Vec<u8>,
+    session_id: Option<String>,
+) -> Result<Vec<u8>, HwHubIoError> {
+    let _session = enter_session(session_id.as_deref()).map_err(|_| HwHubIoError::InternalError)?;
     log::info!("Sending raw frame request to HUB");
     let guard = ps();
     let hub_guard = guard.hub_mut();
@@ -50,7 +66,12 @@ pub fn dbg_send_raw_request_frame(request_frame: Vec<u8>) -> Result<Vec<u8>, HwH
 }
 
 #[command]
-pub fn dbg_send_hub_command(request: HubRequestDto) -> Result<HubResponseDto, HubManagerError> {
+pub fn dbg_send_hub_command(
+    request: HubRequestDto,
+    session_id: Option<String>,
+) -> Result<HubResponseDto, HubManagerError> {
+    let _session =
+        enter_session(session_id.as_deref()).map_err(|_| HubManagerError::InternalError)?;
     log::info!("Sending request to HUB.\n{:#?}", request);
     let guard = ps();
     let mut hub_guard = guard.hub_mut();
@@ -1,100 +1,436 @@
+use crate::core::game::false_start::FalseStartRule;
 use crate::core::game::game_data::GameMode;
+use crate::core::game::journal::HostCommand;
+use crate::core::game::question_chooser::QuestionChooserPolicy;
+use crate::core::game::scoring_rules::{ScoringRules, WrongAnswerPenalty};
 use crate::core::game_controller::game_mut;
 use crate::core::game_entities::GameplayError;
+use crate::core::session::enter_session;
+use crate::host_api::dto::TeamSetupDto;
 use crate::host_api::events::emit_error;
+use crate::player_server::player_server::ps;
 use error_stack::Report;
+use std::path::PathBuf;
 use std::time::Duration;
 use tauri::command;
 
+const DEFAULT_FALSE_START_PENALTY_MS: u64 = 1000;
+
 fn map_game_error(e: Report<GameplayError>) -> GameplayError {
     emit_error(e.to_string());
     log::error!("{:#?}", e);
     e.current_context().clone()
 }
 
+/// Player ids of the frontend are terminal ids. Ids out of their range belong to no player
+fn player_id_of(player_id: i32) -> Result<u8, GameplayError> {
+    u8::try_from(player_id)
+        .map_err(|_| map_game_error(Report::new(GameplayError::PlayerNotPresent(player_id))))
+}
+
 /// Start the game with selected players and game pack
 #[command]
 pub async fn start_new_game(
     round_duration_min: i32,
     is_qcaf_mode: bool,
+    is_pip_enabled: Option<bool>,
+    buzz_window_sec: Option<u64>,
+    answer_time_limit_sec: Option<u64>,
+    wrong_answer_on_timeout: Option<bool>,
+    false_start_window_ms: Option<u64>,
+    false_start_penalty_ms: Option<u64>,
+    wrong_answer_penalty: Option<WrongAnswerPenalty>,
+    is_score_floor_enabled: Option<bool>,
+    is_elimination_enabled: Option<bool>,
+    teams: Option<Vec<TeamSetupDto>>,
+    question_chooser_policy: Option<QuestionChooserPolicy>,
+    question_chooser_timeout_sec: Option<u64>,
+    first_chooser_timeout_sec: Option<u64>,
+    is_first_chooser_random: Option<bool>,
+    session_id: Option<String>,
 ) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
     log::info!("Triggered the game start");
     let mut app = game_mut();
     let game_mode = GameMode {
         round_duration: Duration::from_secs(round_duration_min as u64 * 60),
         question_chooser_answers_first: is_qcaf_mode,
-        pig_in_poke_enabled: false,
+        pig_in_poke_enabled: is_pip_enabled.unwrap_or(true),
+        buzz_window: buzz_window_sec.map(Duration::from_secs),
+        answer_time_limit: answer_time_limit_sec.map(Duration::from_secs),
+        wrong_answer_on_timeout: wrong_answer_on_timeout.unwrap_or(false),
+        false_start: false_start_window_ms.map(|window_ms| FalseStartRule {
+            window: Duration::from_millis(window_ms),
+            penalty: Duration::from_millis(
+                false_start_penalty_ms.unwrap_or(DEFAULT_FALSE_START_PENALTY_MS),
+            ),
+        }),
+        scoring_rules: ScoringRules {
+            wrong_answer_penalty: wrong_answer_penalty.unwrap_or_default(),
+            score_floor_at_zero: is_score_floor_enabled.unwrap_or(false),
+            eliminate_negative_scores: is_elimination_enabled.unwrap_or(true),
+        },
+        question_chooser_policy: question_chooser_policy.unwrap_or_default(),
+        question_chooser_timeout: question_chooser_timeout_sec.map(Duration::from_secs),
+        first_chooser_timeout: first_chooser_timeout_sec.map(Duration::from_secs),
+        random_first_chooser: is_first_chooser_random.unwrap_or(false),
+        teams: teams
+            .unwrap_or_default()
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        manual_buzzers: ps().is_manual_hub(),
     };
-    app.start_new_game(game_mode).map_err(map_game_error)?;
+    app.run_host_command(HostCommand::StartNewGame { game_mode })
+        .map_err(map_game_error)?;
     Ok(())
 }
 
+/// Resumes the game saved before the app was closed
+#[command]
+pub async fn resume_saved_game(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::info!("Triggered the saved game resume");
+    game_mut().resume_saved_game().map_err(map_game_error)
+}
+
+/// Replays the recorded game from its journal. Speed of 2.0 replays it twice as fast
+#[command]
+pub async fn replay_game_journal(
+    journal_path: String,
+    speed: f64,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::info!("Triggered the replay of journal: {}", journal_path);
+    game_mut()
+        .start_replay(PathBuf::from(journal_path), speed)
+        .map_err(map_game_error)
+}
+
 /// Select question to be played
 #[command]
-pub async fn select_question(topic: String, price: i32) -> Result<(), GameplayError> {
+pub async fn select_question(
+    topic: String,
+    price: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
     let mut app = game_mut();
 
-    app.select_question(&topic, price)?;
+    app.run_host_command(HostCommand::SelectQuestion { topic, price })
+        .map_err(map_game_error)?;
     Ok(())
 }
 
-/// Allows events from players to be processed
+/// Allows events from players to be processed.
+/// Doesn't wait for the clicks: the quickest player is picked when hub events arrive
 #[command]
-pub async fn allow_answer() -> Result<(), GameplayError> {
-    let mut guard = game_mut();
-    guard.allow_answer().map_err(map_game_error)?;
-    guard
-        .wait_for_quickest_player_to_click()
+pub async fn allow_answer(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::AllowAnswer)
+        .map_err(map_game_error)
+}
+
+/// Gives the answer to the selected player while waiting for the clicks
+#[command]
+pub async fn request_answer_by_player_id(
+    player_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Host gives the answer to player: {}", player_id);
+
+    game_mut()
+        .run_host_command(HostCommand::RequestAnswer {
+            player_id: player_id_of(player_id)?,
+        })
+        .map_err(map_game_error)
+}
+
+/// Stops waiting for the clicks and returns to the question display
+#[command]
+pub async fn cancel_answer_requests(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::CancelAnswerRequests)
         .map_err(map_game_error)
 }
 
 /// Provide answer to active question
 #[command]
-pub async fn answer_question(answered_correctly: bool) -> Result<(), GameplayError> {
+pub async fn answer_question(
+    answered_correctly: bool,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
     log::debug!("Answered correctly: {answered_correctly}");
 
     game_mut()
-        .answer_question(answered_correctly)
+        .run_host_command(HostCommand::AnswerQuestion { answered_correctly })
         .map_err(map_game_error)
 }
 
 /// Finished current question and set's state to 'show answer'
 #[command]
-pub async fn stop_asking_and_show_answer() -> Result<(), GameplayError> {
+pub async fn stop_asking_and_show_answer(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
     game_mut()
-        .stop_asking_and_show_answer()
+        .run_host_command(HostCommand::StopAskingAndShowAnswer)
         .map_err(map_game_error)
 }
 
 /// Finished current question and set's state to 'show answer'
 #[command]
-pub async fn finish_question() -> Result<(), GameplayError> {
-    game_mut().finish_question().map_err(map_game_error)
+pub async fn finish_question(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::FinishQuestion)
+        .map_err(map_game_error)
 }
 
 /// Initiate next round
 #[command]
-pub async fn init_next_round() -> Result<(), GameplayError> {
-    game_mut().process_end_of_round().map_err(map_game_error)
+pub async fn init_next_round(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::InitNextRound)
+        .map_err(map_game_error)
+}
+
+/// Gives the turn to choose the question to the selected player.
+/// Also picks the first chooser of the round when nobody presses the button
+#[command]
+pub async fn set_question_chooser(
+    player_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Host picks question chooser: {}", player_id);
+
+    game_mut()
+        .run_host_command(HostCommand::SetQuestionChooser {
+            player_id: player_id_of(player_id)?,
+        })
+        .map_err(map_game_error)
+}
+
+/// Gives the turn to choose the question to a random player
+#[command]
+pub async fn pick_random_question_chooser(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::PickRandomQuestionChooser)
+        .map_err(map_game_error)
 }
 
+/// Gives 'pig in poke' question to the selected player
 #[command]
-pub async fn send_pip_victim(victim_id: i32) {
+pub async fn send_pip_victim(
+    victim_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
     log::debug!("Victim id is: {}", victim_id);
+
+    game_mut()
+        .run_host_command(HostCommand::SendPipVictim {
+            victim_id: player_id_of(victim_id)?,
+        })
+        .map_err(map_game_error)
 }
 
+/// Raises the stake for the 'auction' question
 #[command]
-pub async fn finish_game() -> Result<(), GameplayError> {
-    game_mut().finish_game().map_err(map_game_error)
+pub async fn place_auction_bid(
+    player_id: i32,
+    amount: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Player {} bids {}", player_id, amount);
+
+    game_mut()
+        .run_host_command(HostCommand::PlaceAuctionBid {
+            player_id: player_id_of(player_id)?,
+            amount,
+        })
+        .map_err(map_game_error)
 }
 
 #[command]
-pub async fn reset_game() {
+pub async fn place_auction_all_in(
+    player_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Player {} goes all-in", player_id);
+
+    game_mut()
+        .run_host_command(HostCommand::PlaceAuctionAllIn {
+            player_id: player_id_of(player_id)?,
+        })
+        .map_err(map_game_error)
+}
+
+#[command]
+pub async fn pass_auction(player_id: i32, session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Player {} passes", player_id);
+
+    game_mut()
+        .run_host_command(HostCommand::PassAuction {
+            player_id: player_id_of(player_id)?,
+        })
+        .map_err(map_game_error)
+}
+
+/// Finishes bidding and gives the question to the current auction leader
+#[command]
+pub async fn finish_auction(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::FinishAuction)
+        .map_err(map_game_error)
+}
+
+/// Removes the theme from the final round on behalf of the current eliminator
+#[command]
+pub async fn eliminate_final_theme(
+    theme: String,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Eliminating final theme: {}", theme);
+
+    game_mut()
+        .run_host_command(HostCommand::EliminateFinalTheme { theme })
+        .map_err(map_game_error)
+}
+
+/// Stores hidden wager of the final round participant
+#[command]
+pub async fn place_final_wager(
+    player_id: i32,
+    amount: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!("Player {} placed final wager", player_id);
+
+    game_mut()
+        .run_host_command(HostCommand::PlaceFinalWager {
+            player_id: player_id_of(player_id)?,
+            amount,
+        })
+        .map_err(map_game_error)
+}
+
+/// Judges the answer of the final round participant. Scores settle when all answers are judged
+#[command]
+pub async fn judge_final_answer(
+    player_id: i32,
+    answered_correctly: bool,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::debug!(
+        "Player {} answered correctly: {}",
+        player_id,
+        answered_correctly
+    );
+
+    game_mut()
+        .run_host_command(HostCommand::JudgeFinalAnswer {
+            player_id: player_id_of(player_id)?,
+            answered_correctly,
+        })
+        .map_err(map_game_error)
+}
+
+/// Rolls back the last host decision restoring scores, stats and used questions
+#[command]
+pub async fn undo_last_action(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::UndoLastAction)
+        .map_err(map_game_error)
+}
+
+#[command]
+pub async fn finish_game(session_id: Option<String>) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::FinishGame)
+        .map_err(map_game_error)
+}
+
+#[command]
+pub async fn reset_game(session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     let mut app = game_mut();
     app.reset_the_game();
 }
 
 #[command]
-pub async fn edit_player_score(player_id: i32, score: i32) -> Result<(), GameplayError>{
-    game_mut().edit_player_score(player_id, score).map_err(map_game_error)
-}
\ No newline at end of file
+pub async fn edit_player_score(
+    player_id: i32,
+    score: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::EditPlayerScore {
+            player_id: player_id_of(player_id)?,
+            score,
+        })
+        .map_err(map_game_error)
+}
+
+#[command]
+pub async fn add_player(
+    player_id: i32,
+    name: String,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::AddPlayer {
+            player_id: player_id_of(player_id)?,
+            name,
+        })
+        .map_err(map_game_error)
+}
+
+/// Player's score is frozen and the player takes no more part in the game
+#[command]
+pub async fn retire_player(
+    player_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::RetirePlayer {
+            player_id: player_id_of(player_id)?,
+        })
+        .map_err(map_game_error)
+}
+
+/// Moves the player to another terminal, e.g. when the buzzer drops out
+#[command]
+pub async fn move_player(
+    player_id: i32,
+    new_player_id: i32,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    game_mut()
+        .run_host_command(HostCommand::MovePlayer {
+            player_id: player_id_of(player_id)?,
+            new_player_id: player_id_of(new_player_id)?,
+        })
+        .map_err(map_game_error)
+}
@@ -0,0 +1,61 @@
+use crate::core::game::game_state::GameState;
+use crate::core::game::player_profiles::{ProfileStore, ProfileStoreError};
+use crate::core::game_controller::game_mut;
+use crate::core::game_entities::GameplayError;
+use crate::core::session::enter_session;
+use crate::host_api::dto::LeaderboardEntryDto;
+use crate::host_api::events::emit_error;
+use error_stack::Report;
+use tauri::command;
+
+fn map_store_error(e: Report<ProfileStoreError>) -> GameplayError {
+    log::error!("{:#?}", e);
+    emit_error(e.to_string());
+    GameplayError::ProfileStoreFailed
+}
+
+/// Creates or updates the profile of the regular player.
+/// Player on the preferred terminal gets the profile name and icon right away
+#[command]
+pub fn save_player_profile(
+    name: String,
+    icon: String,
+    preferred_term_id: Option<i32>,
+    session_id: Option<String>,
+) -> Result<(), GameplayError> {
+    let _session = enter_session(session_id.as_deref())?;
+    log::info!(
+        "Saving profile of '{}' at terminal {:?}",
+        name,
+        preferred_term_id
+    );
+
+    let mut store = ProfileStore::read().map_err(map_store_error)?;
+    store.save_profile(&name, &icon, preferred_term_id.map(|id| id as u8));
+    store.write().map_err(map_store_error)?;
+
+    let mut app = game_mut();
+    if let GameState::SetupAndLoading(_) = app.game_state {
+        app.apply_player_profiles()
+            .map_err(|e| e.current_context().clone())?;
+    }
+    Ok(())
+}
+
+#[command]
+pub fn delete_player_profile(name: String) -> Result<(), GameplayError> {
+    log::info!("Deleting profile of '{}'", name);
+
+    let mut store = ProfileStore::read().map_err(map_store_error)?;
+    if !store.delete_profile(&name) {
+        return Err(GameplayError::PlayerNotFound);
+    }
+    store.write().map_err(map_store_error)
+}
+
+/// All-time results of the players having a profile. The best player goes first
+#[command]
+pub fn get_leaderboard() -> Result<Vec<LeaderboardEntryDto>, GameplayError> {
+    let store = ProfileStore::read().map_err(map_store_error)?;
+    Ok(store.leaderboard().into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,29 @@
+use crate::core::game_entities::GameplayError;
+use crate::core::session;
+use crate::core::session::SessionError;
+use crate::host_api::events::emit_error;
+use tauri::command;
+
+fn map_session_error(e: SessionError) -> GameplayError {
+    log::error!("{}", e);
+    emit_error(e.to_string());
+    e.into()
+}
+
+/// Opens the stage with its own game, player server and hub.
+/// Commands given with the session id are routed to it
+#[command]
+pub fn open_session(session_id: String) -> Result<(), GameplayError> {
+    session::open_session(&session_id).map_err(map_session_error)
+}
+
+/// Resets the game of the session and releases its hub
+#[command]
+pub fn close_session(session_id: String) -> Result<(), GameplayError> {
+    session::close_session(&session_id).map_err(map_session_error)
+}
+
+#[command]
+pub fn get_session_ids() -> Vec<String> {
+    session::session_ids()
+}
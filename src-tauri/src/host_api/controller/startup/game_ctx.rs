@@ -1,5 +1,6 @@
 use crate::core::game_controller::game_mut;
 use crate::core::game_pack::game_pack_loader::{load_game_pack, GamePackLoadingError};
+use crate::core::session::enter_session;
 use crate::host_api::dto::PackErrorDataDto;
 use crate::host_api::events::emit_pack_info;
 use crate::host_api::events::*;
@@ -8,7 +9,12 @@ use tauri::{command, Window};
 
 /// Load game pack into the game
 #[command]
-pub fn init_game_pack(path: String) -> Result<(), PackErrorDataDto> {
+pub fn init_game_pack(path: String, session_id: Option<String>) -> Result<(), PackErrorDataDto> {
+    let _session = enter_session(session_id.as_deref()).map_err(|e| PackErrorDataDto {
+        path: path.clone(),
+        cause: e.to_string(),
+        details: String::new(),
+    })?;
     log::info!("Obtained package path: {}", path);
 
     let result = load_game_pack(path.as_str());
@@ -50,6 +56,9 @@ fn handle_pack_info_error(
 
 /// Dirty hack to capture window handle
 #[command]
-pub fn init_window_handle(window: Window) {
+pub fn init_window_handle(window: Window, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     set_window(window);
 }
@@ -1,4 +1,5 @@
 use crate::core::game_controller::game;
+use crate::core::session::enter_session;
 use crate::player_server::player_server::ps;
 use tauri::command;
 
@@ -7,7 +8,10 @@ pub mod player_server;
 
 /// To get initial app context
 #[command]
-pub fn request_context_update() {
+pub fn request_context_update(session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     game().request_context_update();
     ps().request_context_update();
 }
@@ -1,3 +1,4 @@
+use crate::core::session::enter_session;
 use crate::host_api::dto::PlayerDto;
 use crate::hub::hub_api::HubType;
 use crate::player_server::player_server::ps_mut;
@@ -5,7 +6,10 @@ use tauri::command;
 
 /// Set hub type to web or serial
 #[command]
-pub fn set_hub_type(hub_type: HubType) {
+pub fn set_hub_type(hub_type: HubType, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     log::debug!("Got request to set hub type: {:?}", hub_type);
     let mut app = ps_mut();
     app.select_hub_type(hub_type);
@@ -13,20 +17,29 @@ pub fn set_hub_type(hub_type: HubType) {
 
 /// Tries to detect hub at given serial port. If successful saves port name
 #[command]
-pub fn discover_hub(path: String) {
+pub fn discover_hub(path: String, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     let mut app = ps_mut();
     app.discover_hub_and_players(path);
 }
 
 /// Calls HUB to set specific radio channel
 #[command]
-pub fn set_hw_hub_radio_channel(channel_id: i32) {
+pub fn set_hw_hub_radio_channel(channel_id: i32, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     log::info!("Got channel id: {channel_id}");
     ps_mut().set_hub_radio_channel(channel_id as u8);
 }
 
 /// Saves players to game context through player server
 #[command]
-pub fn save_players(players: Vec<PlayerDto>) {
+pub fn save_players(players: Vec<PlayerDto>, session_id: Option<String>) {
+    let Ok(_session) = enter_session(session_id.as_deref()) else {
+        return;
+    };
     ps_mut().update_players(&players);
 }
@@ -0,0 +1,42 @@
+use crate::core::game::tournament::{Tournament, TournamentError};
+use crate::core::game_entities::GameplayError;
+use crate::host_api::dto::{TournamentDto, TournamentGameDto};
+use crate::host_api::events::{emit_error, emit_tournament};
+use error_stack::Report;
+use tauri::command;
+
+fn map_tournament_error(e: Report<TournamentError>) -> GameplayError {
+    log::error!("{:#?}", e);
+    emit_error(e.to_string());
+    GameplayError::TournamentFailed
+}
+
+/// Starts the tournament. Results of every finished game count to it until it's discarded.
+/// Pack and players of the current game are given by the tournament
+#[command]
+pub fn create_tournament(
+    name: String,
+    points_per_place: Vec<u32>,
+    games: Vec<TournamentGameDto>,
+) -> Result<(), GameplayError> {
+    log::info!("Creating tournament '{}' of {} games", name, games.len());
+
+    let games = games.into_iter().map(Into::into).collect();
+    let tournament =
+        Tournament::new(name, points_per_place, games).map_err(map_tournament_error)?;
+    tournament.write().map_err(map_tournament_error)?;
+    emit_tournament((&tournament).into());
+    Ok(())
+}
+
+#[command]
+pub fn get_tournament() -> Result<Option<TournamentDto>, GameplayError> {
+    let tournament = Tournament::read().map_err(map_tournament_error)?;
+    Ok(tournament.as_ref().map(Into::into))
+}
+
+#[command]
+pub fn discard_tournament() -> Result<(), GameplayError> {
+    log::info!("Discarding tournament");
+    Tournament::delete().map_err(map_tournament_error)
+}
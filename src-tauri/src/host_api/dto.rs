@@ -1,8 +1,10 @@
+use crate::core::game::countdown::CountdownKind;
 use crate::core::game::ctx::state_processors::show_round_stats::EndGameReason;
 use crate::core::game_entities::{HubStatus, Player, PlayerState};
 use crate::core::game_pack::pack_content_entities::QuestionMediaType;
 use crate::types::Image;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 ////////// Hub Config ///////////
 #[derive(Debug, Default, Serialize, Clone)]
@@ -37,6 +39,7 @@ pub struct PackInfoDto {
     pub packTopics: i32,
     pub packQuestions: i32,
     pub packTopicList: Vec<String>,
+    pub packWarnings: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -82,7 +85,7 @@ pub struct QuestionDto {
     pub answer: Vec<QuestionSceneDto>,
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[allow(non_snake_case)]
 pub enum QuestionType {
     #[default]
@@ -98,6 +101,116 @@ pub struct QuestionSceneDto {
     pub content: String,
 }
 
+////////// Auction ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct AuctionDto {
+    pub nominalPrice: i32,
+    pub participants: Vec<i32>,
+    pub passedPlayers: Vec<i32>,
+    pub leaderId: Option<i32>,
+    pub stake: i32,
+    pub isAllIn: bool,
+}
+
+////////// Final round ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct FinalRoundDto {
+    pub themes: Vec<String>,
+    pub participants: Vec<i32>,
+    pub eliminatorId: Option<i32>,
+    pub wageredPlayers: Vec<i32>,
+    pub judgedPlayers: Vec<i32>,
+}
+
+////////// Round time ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct RoundTimeDto {
+    pub durationSec: i32,
+    pub elapsedSec: i32,
+    pub remainingSec: i32,
+    pub isPaused: bool,
+    pub isExpired: bool,
+}
+
+////////// Countdown ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct CountdownDto {
+    pub kind: Option<CountdownKind>,
+    pub durationSec: i32,
+    pub remainingSec: i32,
+}
+
+////////// False start ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct FalseStartDto {
+    pub playerId: i32,
+    pub playerName: String,
+    pub isLockedOut: bool,
+    pub lockoutMs: i32,
+}
+
+////////// Player profiles ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct LeaderboardEntryDto {
+    pub name: String,
+    pub icon: String,
+    pub preferredTermId: Option<u8>,
+    pub gamesPlayed: u32,
+    pub wins: u32,
+    pub totalScore: i64,
+    /// Share of correct answers from 0 to 1
+    pub accuracy: f32,
+}
+
+////////// Tournament ///////////
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct TournamentGameDto {
+    pub packPath: String,
+    /// Number of the best players seeded when no players are given. Zero seeds everyone
+    pub seats: usize,
+    pub players: Vec<String>,
+    #[serde(default)]
+    pub isFinished: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct StandingDto {
+    pub name: String,
+    pub points: u32,
+    pub gamesPlayed: u32,
+    pub totalScore: i64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct TournamentDto {
+    pub name: String,
+    pub pointsPerPlace: Vec<u32>,
+    pub games: Vec<TournamentGameDto>,
+    pub standings: Vec<StandingDto>,
+    pub currentGameIndex: usize,
+    pub isFinished: bool,
+}
+
+////////// Replay ///////////
+#[derive(Debug, Serialize, Clone)]
+#[allow(non_snake_case)]
+pub struct ReplayResultDto {
+    pub entriesReplayed: usize,
+    /// Replayed game ended with the same scores as the recorded one
+    pub isReproduced: bool,
+    pub expectedScores: BTreeMap<u8, i32>,
+    pub replayedScores: BTreeMap<u8, i32>,
+}
+
 ////////// Round stats ///////////
 #[derive(Debug, Serialize, Clone)]
 #[allow(non_snake_case)]
@@ -106,6 +219,7 @@ pub struct RoundStatsDto {
     pub questionsPlayed: i32,
     pub normalQuestionsPlayed: i32,
     pub pigInPokeQuestionPlayed: i32,
+    pub auctionQuestionPlayed: i32,
     pub totalCorrectAnswers: i32,
     pub totalWrongAnswers: i32,
     pub totalTries: i32,
@@ -123,6 +237,21 @@ pub struct PlayerEndRoundStatsDto {
     pub totalAnswers: i32,
     pub answeredCorrectly: i32,
     pub answeredWrong: i32,
+    /// Reaction times in the round
+    pub reactions: ReactionStatsDto,
+    /// Breakdown of the team result. Empty for the solo player
+    pub members: Vec<PlayerEndRoundStatsDto>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Serialize, Clone)]
+pub struct ReactionStatsDto {
+    pub reactionTimesMs: Vec<u32>,
+    pub meanMs: Option<u32>,
+    pub medianMs: Option<u32>,
+    pub bestMs: Option<u32>,
+    /// Presses which came after the fastest one
+    pub lostRaces: i32,
 }
 
 #[allow(non_snake_case)]
@@ -142,6 +271,10 @@ pub struct PlayerFinalStatsDto {
     icon: Option<Image>,
     score: i32,
     state: PlayerState,
+    /// Reaction times in the whole game
+    reactions: ReactionStatsDto,
+    /// Breakdown of the team result. Empty for the solo player
+    members: Vec<PlayerFinalStatsDto>,
 }
 
 impl From<&Player> for PlayerFinalStatsDto {
@@ -151,9 +284,27 @@ impl From<&Player> for PlayerFinalStatsDto {
             icon: None,
             score: p.stats.score,
             state: p.state.clone(),
+            reactions: (&p.stats.reactions.game).into(),
+            members: vec![],
+        }
+    }
+}
+
+impl PlayerFinalStatsDto {
+    pub fn with_members(self, members: &[Player]) -> Self {
+        Self {
+            members: members.iter().map(Into::into).collect(),
+            ..self
         }
     }
 }
+#[derive(Debug, Deserialize, Clone)]
+#[allow(non_snake_case)]
+pub struct TeamSetupDto {
+    pub name: String,
+    pub memberIds: Vec<u8>,
+}
+
 ////////// HUB DEBUG ///////////
 #[derive(Debug, Deserialize)]
 #[allow(non_snake_case)]
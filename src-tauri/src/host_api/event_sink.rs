@@ -0,0 +1,185 @@
+use crate::core::session::current_session;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, RwLock, RwLockWriteGuard};
+use tauri::Window;
+
+/// Receiver of the events emitted by the game. Any number of sinks can be subscribed at once
+pub trait EventSink: Send + Sync + Debug {
+    fn emit(&self, event: &str, payload: &Value);
+}
+
+/// Handle to unsubscribe the sink with
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SinkId(usize);
+
+#[derive(Debug)]
+struct Subscription {
+    id: SinkId,
+    session_id: String,
+    sink: Arc<dyn EventSink>,
+}
+
+lazy_static::lazy_static! {
+    static ref SUBSCRIPTIONS: RwLock<Vec<Subscription>> = RwLock::new(vec![]);
+    /// Window of every session
+    static ref WINDOW_SINK_IDS: Mutex<HashMap<String, SinkId>> = Mutex::new(HashMap::new());
+}
+
+static NEXT_SINK_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Sink receives the events of the session the calling thread works with
+pub fn subscribe(sink: Box<dyn EventSink>) -> SinkId {
+    let id = SinkId(NEXT_SINK_ID.fetch_add(1, Ordering::Relaxed));
+    let session_id = current_session().id();
+    log::info!(
+        "Subscribing event sink {:?} to session '{}': {:?}",
+        id,
+        session_id,
+        sink
+    );
+    subscriptions_mut().push(Subscription {
+        id,
+        session_id,
+        sink: sink.into(),
+    });
+    id
+}
+
+pub fn unsubscribe(id: SinkId) {
+    log::info!("Unsubscribing event sink {:?}", id);
+    subscriptions_mut().retain(|s| s.id != id);
+}
+
+/// Drops every sink of the closed session
+pub fn unsubscribe_session(session_id: &str) {
+    log::info!("Unsubscribing event sinks of session '{}'", session_id);
+    subscriptions_mut().retain(|s| s.session_id != session_id);
+    WINDOW_SINK_IDS
+        .lock()
+        .expect("Mutex is poisoned")
+        .remove(session_id);
+}
+
+/// Sends the event to every sink of the current session.
+/// Sinks are called outside the lock, so a sink may subscribe or unsubscribe others
+pub fn publish(event: &str, payload: &Value) {
+    let session_id = current_session().id();
+    let sinks: Vec<Arc<dyn EventSink>> = SUBSCRIPTIONS
+        .read()
+        .expect("Mutex is poisoned")
+        .iter()
+        .filter(|s| s.session_id == session_id)
+        .map(|s| s.sink.clone())
+        .collect();
+    if sinks.is_empty() {
+        log::trace!("No event sinks subscribed. Event {} is dropped", event);
+    }
+    sinks.iter().for_each(|sink| sink.emit(event, payload));
+}
+
+/// Front-end window of the current session. A new window replaces the previous one
+pub fn subscribe_window(window: Window) {
+    let mut window_sink_ids = WINDOW_SINK_IDS.lock().expect("Mutex is poisoned");
+    let session_id = current_session().id();
+    if let Some(id) = window_sink_ids.remove(&session_id) {
+        unsubscribe(id);
+    }
+    let id = subscribe(Box::new(WindowSink { window }));
+    window_sink_ids.insert(session_id, id);
+}
+
+fn subscriptions_mut() -> RwLockWriteGuard<'static, Vec<Subscription>> {
+    SUBSCRIPTIONS.write().expect("Mutex is poisoned")
+}
+
+#[derive(Debug)]
+pub struct WindowSink {
+    window: Window,
+}
+
+impl EventSink for WindowSink {
+    fn emit(&self, event: &str, payload: &Value) {
+        if let Err(e) = self.window.emit(event, payload) {
+            log::error!("Failed to send {} to the front-end: {}", event, e);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedEvent {
+    pub event: String,
+    pub payload: Value,
+}
+
+/// Keeps the events in memory, so the game can be observed without the front-end.
+/// Clones share the records, so one clone can be subscribed and the other inspected
+#[derive(Debug, Clone, Default)]
+pub struct RecordingSink {
+    events: Arc<Mutex<Vec<RecordedEvent>>>,
+}
+
+impl RecordingSink {
+    pub fn events(&self) -> Vec<RecordedEvent> {
+        self.events.lock().expect("Mutex is poisoned").clone()
+    }
+
+    /// Payloads of the events of the given type in the order they were emitted
+    pub fn payloads_of(&self, event: &str) -> Vec<Value> {
+        self.events()
+            .into_iter()
+            .filter(|e| e.event == event)
+            .map(|e| e.payload)
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.events.lock().expect("Mutex is poisoned").clear();
+    }
+}
+
+impl EventSink for RecordingSink {
+    fn emit(&self, event: &str, payload: &Value) {
+        self.events
+            .lock()
+            .expect("Mutex is poisoned")
+            .push(RecordedEvent {
+                event: event.to_string(),
+                payload: payload.clone(),
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host_api::events::emit_message;
+    use serde_json::json;
+
+    #[test]
+    fn test_every_sink_receives_the_event_until_unsubscribed() {
+        let first = RecordingSink::default();
+        let second = RecordingSink::default();
+        let first_id = subscribe(Box::new(first.clone()));
+        let second_id = subscribe(Box::new(second.clone()));
+
+        emit_message("Sinks test: both");
+        unsubscribe(first_id);
+        emit_message("Sinks test: second");
+        unsubscribe(second_id);
+
+        let messages = |sink: &RecordingSink| -> Vec<Value> {
+            sink.payloads_of("message")
+                .into_iter()
+                .filter(|p| p.as_str().map_or(false, |m| m.starts_with("Sinks test")))
+                .collect()
+        };
+        assert_eq!(messages(&first), vec![json!("Sinks test: both")]);
+        assert_eq!(
+            messages(&second),
+            vec![json!("Sinks test: both"), json!("Sinks test: second")]
+        );
+    }
+}
@@ -7,13 +7,14 @@ use crate::core::game_controller::game;
 use crate::core::game_entities::{HubStatus, Player};
 use crate::core::game_pack::pack_content_entities::Round;
 use crate::host_api::dto::{
-    EndGameStatsDto, HubConfigDto, PackInfoDto, PlayerDto, PlayersDto, QuestionDto, RoundDto,
-    RoundStatsDto,
+    AuctionDto, CountdownDto, EndGameStatsDto, FalseStartDto, FinalRoundDto, HubConfigDto,
+    PackInfoDto, PlayerDto, PlayersDto, QuestionDto, ReplayResultDto, RoundDto, RoundStatsDto,
+    RoundTimeDto, TournamentDto,
 };
+use crate::host_api::event_sink::{publish, subscribe_window};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::sync::{Arc, Mutex, MutexGuard, RwLock, RwLockReadGuard};
 use tauri::Window;
 
 #[derive(Debug)]
@@ -30,6 +31,13 @@ pub enum Event {
     GameState,
     RoundStats,
     FinalResults,
+    Auction,
+    FinalRound,
+    RoundTime,
+    Countdown,
+    FalseStart,
+    ReplayResult,
+    Tournament,
 }
 
 /// Impl enum to &str conversion
@@ -48,38 +56,32 @@ impl<'a> From<Event> for &'a str {
             Event::GameState => "GameState",
             Event::RoundStats => "RoundStats",
             Event::FinalResults => "FinalResults",
+            Event::Auction => "Auction",
+            Event::FinalRound => "FinalRound",
+            Event::RoundTime => "RoundTime",
+            Event::Countdown => "Countdown",
+            Event::FalseStart => "FalseStart",
+            Event::ReplayResult => "ReplayResult",
+            Event::Tournament => "Tournament",
         }
     }
 }
 
 pub fn emit<S: Serialize + Clone + Debug>(event: Event, message: S) {
-    if let Some(window) = window().as_ref() {
-        log::debug!(
-            "Emitting event of type: {:?}. Payload: {:#?}",
-            event,
-            message
-        );
-        window
-            .emit(event.into(), message)
-            .map_err(|e| format!("Failed to send message: {}", e))
-            .expect("Expected to send message to the front-end")
+    log::debug!(
+        "Emitting event of type: {:?}. Payload: {:#?}",
+        event,
+        message
+    );
+    match serde_json::to_value(message) {
+        Ok(payload) => publish(event.into(), &payload),
+        Err(e) => log::error!("Can't serialize payload of {:?}: {}", event, e),
     }
 }
 
-lazy_static::lazy_static! {
-    static ref WINDOW: Arc<RwLock<Option<Window>>> = Arc::new(RwLock::new(Option::default()));
-}
-
-pub fn window() -> RwLockReadGuard<'static, Option<Window>> {
-    WINDOW
-        .read()
-        .map_err(|e| format!("Mutex is poisoned: {e:#?}"))
-        .expect("Mutex is poisoned")
-}
-
+/// Front-end window becomes one of the event sinks
 pub fn set_window(window: Window) {
-    let mut guard = WINDOW.write().expect("Mutex is poisoned");
-    *guard = Some(window);
+    subscribe_window(window);
 }
 
 /// Generic API
@@ -135,6 +137,34 @@ pub fn emit_final_results(final_results: EndGameStatsDto) {
     emit(Event::FinalResults, final_results);
 }
 
+pub fn emit_auction(auction: AuctionDto) {
+    emit(Event::Auction, auction);
+}
+
+pub fn emit_final_round(final_round: FinalRoundDto) {
+    emit(Event::FinalRound, final_round);
+}
+
+pub fn emit_round_time(round_time: RoundTimeDto) {
+    emit(Event::RoundTime, round_time);
+}
+
+pub fn emit_countdown(countdown: CountdownDto) {
+    emit(Event::Countdown, countdown);
+}
+
+pub fn emit_false_start(false_start: FalseStartDto) {
+    emit(Event::FalseStart, false_start);
+}
+
+pub fn emit_replay_result(replay_result: ReplayResultDto) {
+    emit(Event::ReplayResult, replay_result);
+}
+
+pub fn emit_tournament(tournament: TournamentDto) {
+    emit(Event::Tournament, tournament);
+}
+
 #[allow(non_snake_case)]
 #[derive(Debug, Clone, Serialize)]
 struct GameStateDto {
@@ -1,13 +1,26 @@
+use crate::core::game::auction::Auction;
+use crate::core::game::countdown::Countdown;
+use crate::core::game::final_round::FinalRound;
+use crate::core::game::player_profiles::PlayerProfile;
+use crate::core::game::reaction_stats::ReactionTimes;
+use crate::core::game::replay::Replay;
+use crate::core::game::round_clock::RoundClock;
+use crate::core::game::team::TeamSetup;
+use crate::core::game::tournament::{Standing, Tournament, TournamentGame};
 use crate::core::game_entities::Player;
 use crate::core::game_pack::pack_content_entities::{
-    Atom, PackContent, Question, Round, RoundStats,
+    Atom, PackContent, PackQuestionType, Question, Round,
 };
 use crate::host_api::dto::{
-    HubConfigDto, PlayerEndRoundStatsDto, QuestionDto, QuestionSceneDto, RoundDto, RoundStatsDto,
-    TopicDto,
+    AuctionDto, CountdownDto, FinalRoundDto, HubConfigDto, PlayerEndRoundStatsDto, QuestionDto,
+    QuestionSceneDto, RoundDto, RoundTimeDto, TeamSetupDto, TopicDto,
+};
+use crate::host_api::dto::{
+    LeaderboardEntryDto, PackInfoDto, PlayerDto, QuestionBriefDto, ReactionStatsDto,
+    ReplayResultDto, StandingDto, TournamentDto, TournamentGameDto,
 };
-use crate::host_api::dto::{PackInfoDto, PlayerDto, QuestionBriefDto};
 use crate::hub::hub_api::HubManager;
+use crate::player_server::entities::PsPlayer;
 
 /// Hub manager
 impl From<&Box<dyn HubManager>> for HubConfigDto {
@@ -35,6 +48,16 @@ impl From<&Player> for PlayerDto {
     }
 }
 
+impl From<&PlayerDto> for PsPlayer {
+    fn from(player: &PlayerDto) -> Self {
+        Self {
+            id: player.id,
+            name: Some(player.name.clone()),
+            icon: player.iconPath.clone(),
+        }
+    }
+}
+
 /// Pack content
 impl From<&PackContent> for PackInfoDto {
     fn from(package: &PackContent) -> Self {
@@ -62,6 +85,20 @@ impl From<&PackContent> for PackInfoDto {
             .flat_map(|round| round.topics.values().map(|theme| theme.name.clone()))
             .collect();
 
+        let warnings: Vec<String> = package
+            .rounds
+            .iter()
+            .flat_map(|round| round.topics.values())
+            .flat_map(|topic| topic.questions.values())
+            .filter_map(|q| match &q.pack_question_type {
+                PackQuestionType::Unknown(name) => Some(format!(
+                    "Question of '{}' for {} has unknown type '{}'",
+                    q.topic, q.price, name
+                )),
+                _ => None,
+            })
+            .collect();
+
         PackInfoDto {
             packName: package.name.clone(),
             packAuthor: author,
@@ -69,36 +106,36 @@ impl From<&PackContent> for PackInfoDto {
             packTopics: num_topics,
             packQuestions: num_questions,
             packTopicList: topic_list,
+            packWarnings: warnings,
         }
     }
 }
 
-pub fn game_to_round_stats_dto(
-    round: &Round,
-    stats: &RoundStats,
-    players: Vec<Player>,
-) -> RoundStatsDto {
-    RoundStatsDto {
-        roundName: round.name.to_owned(),
-        questionsPlayed: round.question_count,
-        normalQuestionsPlayed: round.normal_question_count,
-        pigInPokeQuestionPlayed: round.pip_question_count,
-        totalCorrectAnswers: stats.total_correct_answers,
-        totalWrongAnswers: stats.total_wrong_answers,
-        totalTries: stats.total_tries,
-        roundTimeSec: 666,
-        players: players
-            .iter()
-            .map(|p| PlayerEndRoundStatsDto {
-                id: p.term_id as i32,
-                name: p.name.to_owned(),
-                score: p.stats.score,
-                playerIconPath: p.icon.to_owned(),
-                totalAnswers: p.stats.total_tries,
-                answeredCorrectly: p.stats.answered_correctly,
-                answeredWrong: p.stats.answered_wrong,
-            })
-            .collect(),
+impl From<&Player> for PlayerEndRoundStatsDto {
+    fn from(p: &Player) -> Self {
+        PlayerEndRoundStatsDto {
+            id: p.term_id as i32,
+            name: p.name.to_owned(),
+            score: p.stats.score,
+            playerIconPath: p.icon.to_owned(),
+            totalAnswers: p.stats.total_tries,
+            answeredCorrectly: p.stats.answered_correctly,
+            answeredWrong: p.stats.answered_wrong,
+            reactions: (&p.stats.reactions.round).into(),
+            members: vec![],
+        }
+    }
+}
+
+impl From<&ReactionTimes> for ReactionStatsDto {
+    fn from(reactions: &ReactionTimes) -> Self {
+        Self {
+            reactionTimesMs: reactions.times_ms.clone(),
+            meanMs: reactions.mean_ms(),
+            medianMs: reactions.median_ms(),
+            bestMs: reactions.best_ms(),
+            lostRaces: reactions.lost_races,
+        }
     }
 }
 
@@ -168,6 +205,67 @@ impl From<&Question> for QuestionDto {
     }
 }
 
+impl From<&Auction> for AuctionDto {
+    fn from(auction: &Auction) -> Self {
+        let mut passed_players: Vec<i32> =
+            auction.passed_players.iter().map(|&id| id as i32).collect();
+        passed_players.sort();
+
+        Self {
+            nominalPrice: auction.nominal_price,
+            participants: auction.participants.iter().map(|&id| id as i32).collect(),
+            passedPlayers: passed_players,
+            leaderId: auction.leader_id().map(|id| id as i32),
+            stake: auction.stake(),
+            isAllIn: auction
+                .highest_bid
+                .as_ref()
+                .map(|b| b.is_all_in)
+                .unwrap_or(false),
+        }
+    }
+}
+
+impl From<&FinalRound> for FinalRoundDto {
+    fn from(final_round: &FinalRound) -> Self {
+        let to_ids = |ids: Vec<u8>| ids.into_iter().map(|id| id as i32).collect();
+
+        Self {
+            themes: final_round.themes.clone(),
+            participants: to_ids(final_round.participants.clone()),
+            eliminatorId: final_round.eliminator_id().map(|id| id as i32),
+            wageredPlayers: to_ids(final_round.wagered_players()),
+            judgedPlayers: to_ids(final_round.judged_players()),
+        }
+    }
+}
+
+impl From<&RoundClock> for RoundTimeDto {
+    fn from(clock: &RoundClock) -> Self {
+        Self {
+            durationSec: clock.duration().as_secs() as i32,
+            elapsedSec: clock.elapsed().as_secs() as i32,
+            remainingSec: clock.remaining().as_secs() as i32,
+            isPaused: !clock.is_running(),
+            isExpired: clock.is_expired(),
+        }
+    }
+}
+
+impl From<Option<&Countdown>> for CountdownDto {
+    fn from(countdown: Option<&Countdown>) -> Self {
+        Self {
+            kind: countdown.map(|c| c.kind),
+            durationSec: countdown
+                .map(|c| c.duration().as_secs() as i32)
+                .unwrap_or(0),
+            remainingSec: countdown
+                .map(|c| c.remaining().as_secs_f32().ceil() as i32)
+                .unwrap_or(0),
+        }
+    }
+}
+
 impl From<&Atom> for QuestionSceneDto {
     fn from(atom: &Atom) -> Self {
         QuestionSceneDto {
@@ -176,3 +274,84 @@ impl From<&Atom> for QuestionSceneDto {
         }
     }
 }
+
+impl From<TeamSetupDto> for TeamSetup {
+    fn from(dto: TeamSetupDto) -> Self {
+        TeamSetup {
+            name: dto.name,
+            member_ids: dto.memberIds,
+        }
+    }
+}
+
+impl From<&Replay> for ReplayResultDto {
+    fn from(replay: &Replay) -> Self {
+        Self {
+            entriesReplayed: replay.entries_replayed,
+            isReproduced: replay.is_reproduced(),
+            expectedScores: replay.expected_scores.clone().unwrap_or_default(),
+            replayedScores: replay.replayed_scores.clone().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&PlayerProfile> for LeaderboardEntryDto {
+    fn from(profile: &PlayerProfile) -> Self {
+        let history = &profile.history;
+        Self {
+            name: profile.name.clone(),
+            icon: profile.icon.clone(),
+            preferredTermId: profile.preferred_term_id,
+            gamesPlayed: history.games_played,
+            wins: history.wins,
+            totalScore: history.total_score,
+            accuracy: history.accuracy(),
+        }
+    }
+}
+
+impl From<&TournamentGame> for TournamentGameDto {
+    fn from(game: &TournamentGame) -> Self {
+        Self {
+            packPath: game.pack_path.clone(),
+            seats: game.seats,
+            players: game.players.clone(),
+            isFinished: game.is_finished,
+        }
+    }
+}
+
+impl From<TournamentGameDto> for TournamentGame {
+    fn from(dto: TournamentGameDto) -> Self {
+        Self {
+            pack_path: dto.packPath,
+            seats: dto.seats,
+            players: dto.players,
+            is_finished: false,
+        }
+    }
+}
+
+impl From<&Standing> for StandingDto {
+    fn from(standing: &Standing) -> Self {
+        Self {
+            name: standing.name.clone(),
+            points: standing.points,
+            gamesPlayed: standing.games_played,
+            totalScore: standing.total_score,
+        }
+    }
+}
+
+impl From<&Tournament> for TournamentDto {
+    fn from(tournament: &Tournament) -> Self {
+        Self {
+            name: tournament.name.clone(),
+            pointsPerPlace: tournament.points_per_place.clone(),
+            games: tournament.games.iter().map(Into::into).collect(),
+            standings: tournament.standings.iter().map(Into::into).collect(),
+            currentGameIndex: tournament.current_game_index,
+            isFinished: tournament.is_finished(),
+        }
+    }
+}
@@ -1,9 +1,14 @@
 pub mod dto;
+pub mod event_sink;
 pub mod events;
 pub mod mapper;
+pub mod websocket_sink;
 
 pub mod controller {
     pub mod debug_api;
     pub mod gameplay_api;
+    pub mod profiles_api;
+    pub mod session_api;
     pub mod startup;
+    pub mod tournament_api;
 }
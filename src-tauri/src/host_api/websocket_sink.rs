@@ -0,0 +1,170 @@
+use crate::core::session::{current_session, spawn_in_session};
+use crate::host_api::event_sink::EventSink;
+use base64::Engine;
+use serde_json::{json, Value};
+use sha1::{Digest, Sha1};
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::thread::sleep;
+use std::time::Duration;
+
+/// Appended to the key of the client to prove the server speaks WebSocket. See RFC 6455
+const HANDSHAKE_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC11B33";
+const ACCEPT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(2);
+/// Client which doesn't take the event in time is dropped instead of holding the game back
+const SEND_TIMEOUT: Duration = Duration::from_millis(200);
+
+/// Broadcasts the events to WebSocket clients, e.g. a scoreboard on another screen.
+/// Every event is sent as `{"event": <name>, "payload": <payload>}` text message.
+/// Messages of the clients are ignored
+#[derive(Debug)]
+pub struct WebSocketSink {
+    address: SocketAddr,
+    clients: Arc<Mutex<Vec<TcpStream>>>,
+}
+
+impl WebSocketSink {
+    /// Accepts the clients in the background until the session is closed
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        log::info!("Broadcasting events over WebSocket at {}", address);
+
+        let clients = Arc::new(Mutex::new(vec![]));
+        let accepted = clients.clone();
+        spawn_in_session(move || accept_clients(listener, accepted));
+        Ok(Self { address, clients })
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+}
+
+impl EventSink for WebSocketSink {
+    fn emit(&self, event: &str, payload: &Value) {
+        let message = json!({ "event": event, "payload": payload }).to_string();
+        let frame = text_frame(&message);
+        self.clients
+            .lock()
+            .expect("Mutex is poisoned")
+            .retain_mut(|client| match client.write_all(&frame) {
+                Ok(_) => true,
+                Err(e) => {
+                    log::info!(
+                        "WebSocket client {:?} is dropped: {}",
+                        client.peer_addr(),
+                        e
+                    );
+                    false
+                }
+            });
+    }
+}
+
+fn accept_clients(listener: TcpListener, clients: Arc<Mutex<Vec<TcpStream>>>) {
+    while !current_session().is_closed() {
+        match listener.accept() {
+            Ok((stream, address)) => match handshake(stream) {
+                Ok(stream) => {
+                    log::info!("WebSocket client connected from {}", address);
+                    clients.lock().expect("Mutex is poisoned").push(stream);
+                }
+                Err(e) => log::info!("WebSocket handshake with {} failed: {}", address, e),
+            },
+            Err(e) if e.kind() == ErrorKind::WouldBlock => sleep(ACCEPT_POLL_INTERVAL),
+            Err(e) => log::error!("Can't accept WebSocket client: {}", e),
+        }
+    }
+    log::info!("Session is closed. WebSocket clients are disconnected");
+}
+
+/// Answers the HTTP upgrade request of the client
+fn handshake(stream: TcpStream) -> io::Result<TcpStream> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+    stream.set_write_timeout(Some(SEND_TIMEOUT))?;
+
+    let mut key = None;
+    let mut reader = BufReader::new(&stream);
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(io::Error::new(ErrorKind::UnexpectedEof, "Request is cut"));
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("Sec-WebSocket-Key") {
+                key = Some(value.trim().to_string());
+            }
+        }
+    }
+    let key =
+        key.ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "Not a WebSocket request"))?;
+
+    let response = format!(
+        "HTTP/1.1 101 Switching Protocols\r\n\
+        Upgrade: websocket\r\n\
+        Connection: Upgrade\r\n\
+        Sec-WebSocket-Accept: {}\r\n\r\n",
+        accept_key(&key)
+    );
+    (&stream).write_all(response.as_bytes())?;
+    Ok(stream)
+}
+
+fn accept_key(key: &str) -> String {
+    let digest = Sha1::digest(format!("{}{}", key, HANDSHAKE_GUID));
+    base64::engine::general_purpose::STANDARD.encode(digest)
+}
+
+/// Unmasked final frame with the text message. Servers never mask their frames
+fn text_frame(message: &str) -> Vec<u8> {
+    let payload = message.as_bytes();
+    let mut frame = vec![0x81];
+    match payload.len() {
+        len if len < 126 => frame.push(len as u8),
+        len if len <= u16::MAX as usize => {
+            frame.push(126);
+            frame.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            frame.push(127);
+            frame.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_key_of_the_rfc_example() {
+        assert_eq!(
+            accept_key("dGhlIHNhbXBsZSBub25jZQ=="),
+            "s3pPLMBiTxaQ9kYGzzo2BbPE+Ws="
+        );
+    }
+
+    #[test]
+    fn test_frame_length_encoding() {
+        assert_eq!(text_frame("Hi"), vec![0x81, 2, b'H', b'i']);
+
+        let frame = text_frame(&"a".repeat(300));
+        assert_eq!(&frame[..4], &[0x81, 126, 1, 44]);
+        assert_eq!(frame.len(), 304);
+
+        let frame = text_frame(&"a".repeat(70_000));
+        assert_eq!(frame[1], 127);
+        assert_eq!(&frame[2..10], &70_000u64.to_be_bytes());
+    }
+}
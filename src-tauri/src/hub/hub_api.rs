@@ -15,6 +15,8 @@ pub enum HubType {
     #[default]
     HwHub,
     WebHub,
+    /// No buzzers. Players are created by the host
+    Manual,
 }
 
 #[derive(Debug, Clone, Serialize, Error)]
@@ -107,6 +109,11 @@ pub trait HubManager: Debug + Send + Sync {
     fn ping_terminal(&self, _term_id: u8) -> Result<(), HubManagerError> {
         Err(Report::new(HubManagerError::ApiNotSupported))
     }
+
+    // Manual-specific
+    fn set_players(&mut self, _players: Vec<PsPlayer>) -> Result<(), HubManagerError> {
+        Err(Report::new(HubManagerError::ApiNotSupported))
+    }
 }
 
 /// Misc
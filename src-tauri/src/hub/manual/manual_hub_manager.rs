@@ -0,0 +1,69 @@
+use error_stack::Result;
+use rgb::RGB8;
+
+use crate::core::game_entities::HubStatus;
+use crate::hub::hub_api::{calc_current_epoch_ms, HubManager};
+use crate::hub::hub_api::{HubManagerError, PlayerEvent, TermButtonState};
+use crate::player_server::entities::PsPlayer;
+
+const MANUAL_HUB_ADDRESS: &str = "manual";
+
+/// 'Hot seat' hub for the games without buzzers.
+/// Players are created by the host and the host gives the answers, so there are no clicks
+#[derive(Debug, Default)]
+pub struct ManualHubManager {
+    players: Vec<PsPlayer>,
+}
+
+impl HubManager for ManualHubManager {
+    fn hub_address(&self) -> String {
+        MANUAL_HUB_ADDRESS.to_string()
+    }
+
+    fn probe(&mut self, _port: &str) -> Result<(), HubManagerError> {
+        log::info!("Manual hub needs no device. Nothing to probe");
+        Ok(())
+    }
+
+    fn hub_status(&self) -> HubStatus {
+        HubStatus::Detected
+    }
+
+    fn discover_players(&mut self) -> Result<Vec<PsPlayer>, HubManagerError> {
+        Ok(self.players.clone())
+    }
+
+    fn calc_hub_timestamp(&self) -> Result<u32, HubManagerError> {
+        calc_current_epoch_ms()
+    }
+
+    fn set_hub_timestamp(&self, _timestamp: u32) -> Result<(), HubManagerError> {
+        Ok(())
+    }
+
+    fn set_term_light_color(&self, _term_id: u8, _color: RGB8) -> Result<(), HubManagerError> {
+        Ok(())
+    }
+
+    fn set_term_feedback_led(
+        &self,
+        _term_id: u8,
+        _state: &TermButtonState,
+    ) -> Result<(), HubManagerError> {
+        Ok(())
+    }
+
+    fn read_event_queue(&self) -> Result<Vec<PlayerEvent>, HubManagerError> {
+        Ok(vec![])
+    }
+
+    fn available_ports(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn set_players(&mut self, players: Vec<PsPlayer>) -> Result<(), HubManagerError> {
+        log::info!("Host created {} players", players.len());
+        self.players = players;
+        Ok(())
+    }
+}
@@ -9,6 +9,9 @@ pub mod hw {
         pub mod hw_hub_device;
     }
 }
+pub mod manual {
+    pub mod manual_hub_manager;
+}
 pub mod web {
     pub mod web_hub_api;
     pub mod web_hub_manager;
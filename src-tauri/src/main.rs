@@ -7,9 +7,12 @@ use std::env;
 use svojak_app::core::game_controller::game;
 use svojak_app::host_api::controller::debug_api::*;
 use svojak_app::host_api::controller::gameplay_api::*;
+use svojak_app::host_api::controller::profiles_api::*;
+use svojak_app::host_api::controller::session_api::*;
 use svojak_app::host_api::controller::startup::game_ctx::*;
 use svojak_app::host_api::controller::startup::player_server::*;
 use svojak_app::host_api::controller::startup::*;
+use svojak_app::host_api::controller::tournament_api::*;
 
 fn main() {
     if env::var("RUST_LOG").is_err() {
@@ -27,6 +30,10 @@ fn main() {
             init_window_handle,
             // Event requests
             request_context_update,
+            // Session API
+            open_session,
+            close_session,
+            get_session_ids,
             // Startup API
             set_hub_type,
             discover_hub,
@@ -34,17 +41,42 @@ fn main() {
             save_players,
             init_game_pack,
             start_new_game,
+            resume_saved_game,
+            replay_game_journal,
+            // Player profiles API
+            save_player_profile,
+            delete_player_profile,
+            get_leaderboard,
+            // Tournament API
+            create_tournament,
+            get_tournament,
+            discard_tournament,
             // Gameplay API
             select_question,
             allow_answer,
+            request_answer_by_player_id,
+            cancel_answer_requests,
             answer_question,
+            set_question_chooser,
+            pick_random_question_chooser,
             send_pip_victim,
+            place_auction_bid,
+            place_auction_all_in,
+            pass_auction,
+            finish_auction,
+            eliminate_final_theme,
+            place_final_wager,
+            judge_final_answer,
             stop_asking_and_show_answer,
             finish_question,
             init_next_round,
+            undo_last_action,
             finish_game,
             reset_game,
             edit_player_score,
+            add_player,
+            retire_player,
+            move_player,
             // Debug API
             dbg_setup_hub_connection,
             dbg_send_raw_request_frame,
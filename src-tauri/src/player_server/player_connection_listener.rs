@@ -1,5 +1,6 @@
 use crate::core::game_controller::game_mut;
 use crate::core::game_entities::{GameplayError, DEFAULT_ICON};
+use crate::core::session::current_session;
 use crate::host_api::events::emit_message;
 use crate::hub::hub_api::HubManager;
 use crate::player_server::entities::PsPlayer;
@@ -14,7 +15,7 @@ pub fn run_player_discovery_loop(
     players_arc: Arc<RwLock<Box<Vec<PsPlayer>>>>,
 ) {
     let mut players = vec![];
-    loop {
+    while !current_session().is_closed() {
         players = discover_and_save_players(players, &hub);
 
         players_arc.swap(Box::new(players.clone()));
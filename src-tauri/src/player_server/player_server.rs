@@ -1,34 +1,35 @@
-use crate::core::game_controller::game;
+use crate::core::game_controller::game_mut;
 use crate::core::game_entities::{GameplayError, Player, PlayerState};
+use crate::core::session::{current_session, spawn_in_session};
 use crate::host_api::dto::PlayerDto;
 use crate::host_api::events::{emit_error, emit_hub_config};
-use crate::hub::hub_api::{HubManager, HubManagerError, HubType, PlayerEvent};
+use crate::hub::hub_api::{HubManager, HubManagerError, HubType};
 use crate::hub::hw::hw_hub_manager::HwHubManager;
+use crate::hub::manual::manual_hub_manager::ManualHubManager;
 use crate::hub::web::web_hub_manager::WebHubManager;
 use crate::player_server::entities::PsPlayer;
 use crate::player_server::player_connection_listener::run_player_discovery_loop;
 use crate::to_factored_ms;
 use crate::types::{ArcRwBox, Swap};
 use error_stack::Report;
+use rgb::RGB8;
 use std::ops::Deref;
 use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
-use std::thread;
 use std::thread::{sleep, JoinHandle};
 use std::time::Duration;
 
-lazy_static::lazy_static! {
-    static ref PLAYER_SERVER: Arc<RwLock<PlayerServer >> = Arc::new(RwLock::new(PlayerServer::default()));
-}
-
+/// Player server of the session the calling thread works with
 pub fn ps_mut() -> RwLockWriteGuard<'static, PlayerServer> {
-    PLAYER_SERVER
+    current_session()
+        .player_server()
         .write()
         .map_err(|e| format!("Mutex is poisoned: {e:#?}"))
         .expect("Mutex is poisoned")
 }
 
 pub fn ps() -> RwLockReadGuard<'static, PlayerServer> {
-    PLAYER_SERVER
+    current_session()
+        .player_server()
         .read()
         .map_err(|e| format!("Mutex is poisoned: {e:#?}"))
         .expect("Mutex is poisoned")
@@ -86,6 +87,17 @@ impl PlayerServer {
             })
             .expect("Poisoned")
     }
+    /// Errors are only logged as the terminal color doesn't affect the game
+    pub fn set_term_light_color(&self, term_id: u8, color: RGB8) {
+        if let Err(e) = self.hub().set_term_light_color(term_id, color) {
+            log::error!("Can't set terminal {} light color: {:?}", term_id, e);
+        }
+    }
+
+    pub fn is_manual_hub(&self) -> bool {
+        self.hub_type == HubType::Manual
+    }
+
     pub fn set_hub_radio_channel(&self, channel_id: u8) {
         let mut hub_guard = self.hub_mut();
 
@@ -117,6 +129,10 @@ impl PlayerServer {
                 log::info!("||| --> Selecting WEB hub <---");
                 self.hub.swap(Box::<WebHubManager>::default());
             }
+            HubType::Manual => {
+                log::info!("||| --> Selecting MANUAL hub <---");
+                self.hub.swap(Box::<ManualHubManager>::default());
+            }
         }
         self.hub_type = hub_type;
         emit_hub_config(self.hub().deref().into());
@@ -157,6 +173,14 @@ impl PlayerServer {
 
         log::info!("Converted players: {:#?}", player_entities);
 
+        if self.is_manual_hub() {
+            let ps_players = players.iter().map(Into::into).collect();
+            if let Err(e) = self.hub_mut().set_players(ps_players) {
+                log::error!("Can't create players on manual hub: {:?}", e);
+                emit_error(e.to_string());
+            }
+        }
+
         // let players = players.iter().map(|p| (p.term_id, p.clone())).collect();
         // self.game_state.game_mut().set_players(players);
         // No emit_players required, as we just set them, but I'll do it anyway to maintain consistency
@@ -174,7 +198,7 @@ impl PlayerServer {
         let hub_arc = self.hub.clone();
         let players_arc = self.players.clone();
         log::info!("Starting player polling thread");
-        let handle = thread::spawn(move || run_player_discovery_loop(hub_arc, players_arc));
+        let handle = spawn_in_session(move || run_player_discovery_loop(hub_arc, players_arc));
 
         log::info!("Saving new thread handle");
         self.player_poling_thread_handle = Some(handle)
@@ -187,10 +211,9 @@ impl PlayerServer {
         }
         log::info!("Starting event listener");
 
-        let arc = { game().get_events_handle() };
         let hub_arc = self.hub.clone();
-        let handle = thread::spawn(move || {
-            listen_hub_events(hub_arc, arc);
+        let handle = spawn_in_session(move || {
+            listen_hub_events(hub_arc);
         });
         log::info!("Saving new event listener thread handle");
         self.event_poling_thread_handle = Some(handle)
@@ -199,11 +222,8 @@ impl PlayerServer {
 
 const EVT_POLLING_INTERVAL_MS: u64 = to_factored_ms!(200);
 
-fn listen_hub_events(
-    hub: Arc<RwLock<Box<dyn HubManager>>>,
-    events_arc: Arc<RwLock<Vec<PlayerEvent>>>,
-) {
-    loop {
+fn listen_hub_events(hub: Arc<RwLock<Box<dyn HubManager>>>) {
+    while !current_session().is_closed() {
         sleep(Duration::from_millis(EVT_POLLING_INTERVAL_MS));
         log::debug!("### New event listener iteration ###");
         let hub_guard = hub.read().expect("Mutex is poisoned");
@@ -236,12 +256,10 @@ fn listen_hub_events(
             log::debug!("New player event received: {:#?}. Pushing to the events", e);
         });
 
+        // Hub lock is released before the game lock to let game handlers use the hub
+        drop(hub_guard);
         log::debug!("Pushing events to the game");
-        // game().push_events(events);
-        events_arc
-            .write()
-            .expect("Expected to be able acquire write lock on events")
-            .extend(events);
+        game_mut().push_events(events);
     }
 }
 // self.hub_type = context.hub_type;
@@ -1,11 +1,14 @@
 import {invoke} from "@tauri-apps/api/tauri";
+import {get} from "svelte/store";
 import {isRunningInTauri} from "./misc.js";
+import {currentSessionIdStore} from "./stores.js";
 
 export async function callBackend(apiCommand, params) {
     if (!isRunningInTauri()) {
         console.warn(`No Tauri context!\nSkipping Tauri API call: '${apiCommand}' with payload: '${JSON.stringify(params)}'`);
         return;
     }
+    params = {sessionId: get(currentSessionIdStore), ...params};
     console.log(`Calling: ${apiCommand} with params`, params)
     return await invoke(apiCommand, params)
 }
@@ -16,6 +19,11 @@ export const TauriApiCommand = {
     REQUEST_CONTEXT_UPDATE: 'request_context_update',
     IS_DEBUG_MODE: 'is_debug_mode',
 
+    // Session API
+    OPEN_SESSION: 'open_session',
+    CLOSE_SESSION: 'close_session',
+    GET_SESSION_IDS: 'get_session_ids',
+
     // Startup API
     SET_HUB_TYPE: 'set_hub_type',
     DISCOVER_HUB: 'discover_hub',
@@ -23,20 +31,47 @@ export const TauriApiCommand = {
     SAVE_PLAYERS: 'save_players',
     INIT_GAME_PACK: 'init_game_pack',
     START_NEW_GAME: 'start_new_game',
+    RESUME_SAVED_GAME: 'resume_saved_game',
+    REPLAY_GAME_JOURNAL: 'replay_game_journal',
+
+    // Player profiles API
+    SAVE_PLAYER_PROFILE: 'save_player_profile',
+    DELETE_PLAYER_PROFILE: 'delete_player_profile',
+    GET_LEADERBOARD: 'get_leaderboard',
+
+    // Tournament API
+    CREATE_TOURNAMENT: 'create_tournament',
+    GET_TOURNAMENT: 'get_tournament',
+    DISCARD_TOURNAMENT: 'discard_tournament',
 
     // Gameplay API
     SELECT_QUESTION: 'select_question',
     ALLOW_ANSWER: 'allow_answer',
+    REQUEST_ANSWER_BY_PLAYER_ID: 'request_answer_by_player_id',
+    CANCEL_ANSWER_REQUESTS: 'cancel_answer_requests',
     ANSWER_QUESTION: 'answer_question',
     STOP_ASKING_AND_SHOW_ANSWER: 'stop_asking_and_show_answer',
     FINISH_QUESTION: 'finish_question',
     INIT_NEXT_ROUND: 'init_next_round',
+    SET_QUESTION_CHOOSER: 'set_question_chooser',
+    PICK_RANDOM_QUESTION_CHOOSER: 'pick_random_question_chooser',
     SEND_PIP_VICTIM: 'send_pip_victim',
+    PLACE_AUCTION_BID: 'place_auction_bid',
+    PLACE_AUCTION_ALL_IN: 'place_auction_all_in',
+    PASS_AUCTION: 'pass_auction',
+    FINISH_AUCTION: 'finish_auction',
+    ELIMINATE_FINAL_THEME: 'eliminate_final_theme',
+    PLACE_FINAL_WAGER: 'place_final_wager',
+    JUDGE_FINAL_ANSWER: 'judge_final_answer',
     GET_ACTIVE_PLAYER_ID: 'get_active_player_id',
     IS_ALLOW_ANSWER_REQUIRED: 'is_allow_answer_required',
     FETCH_ROUND_STATS: 'fetch_round_stats',
+    UNDO_LAST_ACTION: 'undo_last_action',
     FINISH_GAME: 'finish_game',
     RESET_GAME: 'reset_game',
+    ADD_PLAYER: 'add_player',
+    RETIRE_PLAYER: 'retire_player',
+    MOVE_PLAYER: 'move_player',
 
     // Debug API
     DBG_SET_GAME_STATE: 'dbg_set_game_state',
@@ -51,6 +86,20 @@ export const TauriApiCommand = {
 export const HubType = {
     HwHub: 'HwHub',
     WebHub: 'WebHub',
+    Manual: 'Manual',
+}
+
+export const WrongAnswerPenalty = {
+    FullPrice: 'FullPrice',
+    HalfPrice: 'HalfPrice',
+    NoPenalty: 'NoPenalty',
+}
+
+export const QuestionChooserPolicy = {
+    LastCorrectAnswerer: 'LastCorrectAnswerer',
+    RoundRobin: 'RoundRobin',
+    LowestScore: 'LowestScore',
+    HostPick: 'HostPick',
 }
 
 export const HubStatusOptions = {
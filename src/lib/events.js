@@ -4,13 +4,20 @@ import {callBackend, TauriApiCommand} from "./commands.js";
 import {isRunningInTauri} from "./misc.js";
 import {onDestroy, onMount} from "svelte";
 import {
+    currentAuctionStore,
+    currentCountdownStore,
+    currentFalseStartStore,
+    currentReplayResultStore,
+    currentTournamentStore,
     currentFinalResultsStore,
+    currentFinalRoundStore,
     currentGameStateStore,
     currentHubConfigStore,
     currentPackInfoStore,
     currentPlayersStore,
     currentQuestionStore,
     currentRoundStatsStore,
+    currentRoundTimeStore,
     currentRoundStore
 } from "./stores.js";
 
@@ -23,6 +30,13 @@ export const TauriEvents = {
     GameState: "GameState",
     RoundStats: "RoundStats",
     FinalResults: "FinalResults",
+    Auction: "Auction",
+    FinalRound: "FinalRound",
+    RoundTime: "RoundTime",
+    Countdown: "Countdown",
+    FalseStart: "FalseStart",
+    ReplayResult: "ReplayResult",
+    Tournament: "Tournament",
 }
 
 export async function initEventListeners() {
@@ -34,6 +48,13 @@ export async function initEventListeners() {
     listenAndStoreEvent(TauriEvents.GameState, currentGameStateStore);
     listenAndStoreEvent(TauriEvents.RoundStats, currentRoundStatsStore);
     listenAndStoreEvent(TauriEvents.FinalResults, currentFinalResultsStore);
+    listenAndStoreEvent(TauriEvents.Auction, currentAuctionStore);
+    listenAndStoreEvent(TauriEvents.FinalRound, currentFinalRoundStore);
+    listenAndStoreEvent(TauriEvents.RoundTime, currentRoundTimeStore);
+    listenAndStoreEvent(TauriEvents.Countdown, currentCountdownStore);
+    listenAndStoreEvent(TauriEvents.FalseStart, currentFalseStartStore);
+    listenAndStoreEvent(TauriEvents.ReplayResult, currentReplayResultStore);
+    listenAndStoreEvent(TauriEvents.Tournament, currentTournamentStore);
 
     console.log("################################################");
     console.log("##### ALL EVENT LISTENERS HAS BEEN LOADED ######");
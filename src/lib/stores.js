@@ -25,6 +25,8 @@ export const GameState = {
     SetupAndLoading: 'SetupAndLoading',
     PickFirstQuestionChooser: 'PickFirstQuestionChooser',
     ChooseQuestion: 'ChooseQuestion',
+    PickPipVictim: 'PickPipVictim',
+    AuctionBidding: 'AuctionBidding',
     DisplayQuestion: 'DisplayQuestion',
     WaitingForAnswerRequests: 'WaitingForAnswerRequests',
     AnswerAttemptReceived: 'AnswerAttemptReceived',
@@ -32,6 +34,9 @@ export const GameState = {
     CheckEndOfRound: 'CheckEndOfRound',
     ShowRoundStats: 'ShowRoundStats',
     StartNextRound: 'StartNextRound',
+    EliminateFinalThemes: 'EliminateFinalThemes',
+    PlaceFinalWagers: 'PlaceFinalWagers',
+    AnswerFinalQuestion: 'AnswerFinalQuestion',
     EndTheGame: 'EndTheGame',
 }
 
@@ -109,6 +114,7 @@ const mockPackInfo = {
         'Movies',
         'Fallout',
     ],
+    packWarnings: [],
 };
 
 const mockRound = {
@@ -209,6 +215,7 @@ let roundStatsMock = {
     questionsPlayed: 4,
     normalQuestionsPlayed: 3,
     pigInPokeQuestionPlayed: 1,
+    auctionQuestionPlayed: 0,
     totalCorrectAnswers: 4,
     totalWrongAnswers: 3,
     totalTries: 7,
@@ -304,7 +311,22 @@ export const currentQuestionStore = writable(mockQuestion);
 export const currentGameStateStore = writable({gameState: GameState.SetupAndLoading});
 export const currentRoundStatsStore = writable(roundStatsMock);
 export const currentFinalResultsStore = writable(endGameStatsMock);
+export const currentAuctionStore = writable({});
+export const currentFinalRoundStore = writable({});
+export const currentRoundTimeStore = writable({
+    durationSec: 0,
+    elapsedSec: 0,
+    remainingSec: 0,
+    isPaused: true,
+    isExpired: false,
+});
+export const currentCountdownStore = writable({kind: null, durationSec: 0, remainingSec: 0});
+export const currentFalseStartStore = writable({playerId: 0, playerName: "", isLockedOut: false, lockoutMs: 0});
+export const currentReplayResultStore = writable({entriesReplayed: 0, isReproduced: false, expectedScores: {}, replayedScores: {}});
+export const currentTournamentStore = writable({name: '', pointsPerPlace: [], games: [], standings: [], currentGameIndex: 0, isFinished: false});
 export const isDebugMode = writable(false);
+// Game session the commands go to. 'default' is always open
+export const currentSessionIdStore = writable('default');
 
 console.log("################################################");
 console.log("########## ALL STORES HAS BEEN LOADED ##########");